@@ -0,0 +1,37 @@
+//! Criterion benchmarks of a day's solution against its puzzle input.
+//!
+//! Each day crate has a `benches/solution.rs` that hands its `Solution` to
+//! [`bench_solution`], so `cargo bench -p day14` times parsing and both parts
+//! of day 14 on `day14/input`.
+use std::path::Path;
+
+use criterion::Criterion;
+
+use crate::solution::{Part, Solution};
+
+/// The input each day is benchmarked against, relative to its crate.
+pub const INPUT: &str = "input";
+
+/// Benchmarks `parse`, `part1` and `part2` of `S` on the input in `dir`, as
+/// a group named after the day.
+///
+/// Some solvers take seconds per run, so every group takes criterion's
+/// smallest sample size.
+pub fn bench_solution<S: Solution>(c: &mut Criterion, dir: &str) {
+    bench_solution_on::<S>(c, dir, INPUT)
+}
+
+/// As `bench_solution`, on `file` in `dir`, for days whose puzzle
+/// input is not checked in.
+pub fn bench_solution_on<S: Solution>(c: &mut Criterion, dir: &str, file: &str) {
+    let path = Path::new(dir).join(file);
+    let input = S::parse(&path).unwrap_or_else(|err| panic!("cannot parse {}: {}", path.display(), err));
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(&path).unwrap()));
+    for part in Part::BOTH {
+        group.bench_function(format!("part{}", part), |b| b.iter(|| S::solve(&input, part).unwrap()));
+    }
+    group.finish();
+}
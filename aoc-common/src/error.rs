@@ -0,0 +1,125 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input::STDIN;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Error raised while loading or solving a puzzle, tagged with the input file
+/// it came from and, where it makes sense, the 1-based line and column.
+///
+/// Parsers working on a single line fill in the column, and the loader that
+/// called them fills in the line and path.
+#[derive(Debug)]
+pub struct AocError {
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(io::Error),
+    /// A token that could not be understood, e.g. a letter where a number belongs.
+    InvalidToken { token: String, message: String },
+    /// The input stopped before something it needed.
+    UnexpectedEof { expected: String },
+    /// Input that is well formed token by token, but not put together correctly.
+    Format(String),
+    /// Input that parses, but for which the puzzle has no answer.
+    NoSolution(String),
+}
+
+impl AocError {
+    pub fn new(kind: ErrorKind) -> AocError {
+        AocError { path: None, line: None, column: None, kind }
+    }
+
+    pub fn invalid_token<T: ToString, M: ToString>(token: T, message: M) -> AocError {
+        AocError::new(ErrorKind::InvalidToken { token: token.to_string(), message: message.to_string() })
+    }
+
+    pub fn unexpected_eof<E: ToString>(expected: E) -> AocError {
+        AocError::new(ErrorKind::UnexpectedEof { expected: expected.to_string() })
+    }
+
+    pub fn format<M: ToString>(message: M) -> AocError {
+        AocError::new(ErrorKind::Format(message.to_string()))
+    }
+
+    pub fn no_solution<M: ToString>(message: M) -> AocError {
+        AocError::new(ErrorKind::NoSolution(message.to_string()))
+    }
+
+    pub fn io(path: &Path, err: io::Error) -> AocError {
+        AocError::new(ErrorKind::Io(err)).in_file(path)
+    }
+
+    /// Sets the file the error came from, unless it is already known.
+    pub fn in_file(mut self, path: &Path) -> AocError {
+        self.path.get_or_insert_with(|| path.to_path_buf());
+        self
+    }
+
+    /// Sets the line the error is on, unless it is already known.
+    pub fn at_line(mut self, line: usize) -> AocError {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column the error is at, unless it is already known.
+    pub fn at_column(mut self, column: usize) -> AocError {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Locates an error raised while parsing a token that starts at `column`.
+    /// A column within the token is shifted along, and a missing one becomes
+    /// the start of the token.
+    pub fn at_token(mut self, column: usize) -> AocError {
+        self.column = Some(self.column.map_or(column, |offset| column + offset - 1));
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location: Vec<String> = self.path.iter()
+                                        .map(|path| match path.to_str() {
+                                            Some(STDIN) => "<stdin>".to_string(),
+                                            _ => path.display().to_string(),
+                                        })
+                                        .chain(self.line.map(|line| line.to_string()))
+                                        .chain(self.line.and(self.column).map(|column| column.to_string()))
+                                        .collect();
+        if location.is_empty() {
+            write!(f, "{}", self.kind)
+        }
+        else {
+            write!(f, "{}: {}", location.join(":"), self.kind)
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Io(err) => write!(f, "{}", err),
+            ErrorKind::InvalidToken { token, message } => write!(f, "invalid token {:?}: {}", token, message),
+            ErrorKind::UnexpectedEof { expected } => write!(f, "unexpected end of input, expected {}", expected),
+            ErrorKind::Format(message) => write!(f, "{}", message),
+            ErrorKind::NoSolution(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// `(x, y)` position in a grid, with `x` the column and `y` the row.
+pub type Coord = (usize, usize);
+/// Signed position, for stepping off the edge of a grid without underflow.
+pub type ICoord = (isize, isize);
+
+const NEIGHBOURS_4: [ICoord; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const NEIGHBOURS_8: [ICoord; 8] = [(-1, -1), (0, -1), (1, -1),
+                                   (-1, 0),           (1, 0),
+                                   (-1, 1),  (0, 1),  (1, 1)];
+
+/// A single character cell of a grid, used to parse and display grids.
+pub trait Cell: Sized {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+/// Single decimal digits. Values that do not fit in one digit display as `*`.
+impl Cell for u32 {
+    fn from_char(c: char) -> Option<u32> {
+        c.to_digit(10)
+    }
+
+    fn to_char(&self) -> char {
+        std::char::from_digit(*self, 10).unwrap_or('*')
+    }
+}
+
+/// `#` for set and `.` for unset, as in the image and paper puzzles.
+impl Cell for bool {
+    fn from_char(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// A dense, row-major 2D grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Grid<T> {
+        assert_eq!(data.len(), rows * cols, "grid data does not match {}x{}", cols, rows);
+        Grid { rows, cols, data }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Grid<T> where T: Clone {
+        Grid::new(rows, cols, vec![value; rows * cols])
+    }
+
+    /// Builds a grid from equal length rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "grid rows differ in length");
+        Grid::new(rows.len(), cols, rows.into_iter().flatten().collect())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn at(&self, (x, y): Coord) -> &T {
+        &self.data[self.index_of((x, y))]
+    }
+
+    pub fn at_mut(&mut self, (x, y): Coord) -> &mut T {
+        let idx = self.index_of((x, y));
+        &mut self.data[idx]
+    }
+
+    /// Bounds checked access, `None` anywhere off the grid.
+    pub fn get(&self, coord: ICoord) -> Option<&T> {
+        self.checked_coord(coord).map(|coord| self.at(coord))
+    }
+
+    pub fn get_mut(&mut self, coord: ICoord) -> Option<&mut T> {
+        self.checked_coord(coord).map(move |coord| self.at_mut(coord))
+    }
+
+    pub fn contains(&self, coord: ICoord) -> bool {
+        self.checked_coord(coord).is_some()
+    }
+
+    pub fn checked_coord(&self, (x, y): ICoord) -> Option<Coord> {
+        if (0..self.cols as isize).contains(&x) && (0..self.rows as isize).contains(&y) {
+            Some((x as usize, y as usize))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn index_of(&self, (x, y): Coord) -> usize {
+        assert!(x < self.cols && y < self.rows, "{:?} is outside a {}x{} grid", (x, y), self.cols, self.rows);
+        y * self.cols + x
+    }
+
+    pub fn coord_of(&self, idx: usize) -> Coord {
+        (idx % self.cols, idx / self.cols)
+    }
+
+    /// Coordinates of every cell, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let cols = self.cols;
+        (0..self.data.len()).map(move |idx| (idx % cols, idx / cols))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Every cell paired with its coordinate, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.data.iter())
+    }
+
+    /// The up, down, left and right neighbours that lie on the grid.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets(coord, &NEIGHBOURS_4)
+    }
+
+    /// As `neighbours4`, plus the diagonals.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        self.offsets(coord, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(&'a self, (x, y): Coord, offsets: &'a [ICoord]) -> impl Iterator<Item = Coord> + 'a {
+        offsets.iter()
+               .filter_map(move |(dx, dy)| self.checked_coord((x as isize + dx, y as isize + dy)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.cols..(y + 1) * self.cols]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.cols);
+        self.data.iter().skip(x).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> std::slice::Chunks<'_, T> {
+        self.data.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.data.iter().map(f).collect())
+    }
+
+    /// A copy of the grid surrounded by `pad` rows and columns of `value`.
+    pub fn pad(&self, value: T, pad: usize) -> Grid<T> where T: Clone {
+        let cols = self.cols + pad * 2;
+        let mut padded = vec![value.clone(); cols * pad];
+        self.iter_rows()
+            .for_each(|row| {
+                padded.extend(std::iter::repeat_n(value.clone(), pad));
+                padded.extend_from_slice(row);
+                padded.extend(std::iter::repeat_n(value.clone(), pad));
+            });
+        padded.extend(std::iter::repeat_n(value, cols * pad));
+
+        Grid::new(self.rows + pad * 2, cols, padded)
+    }
+
+    /// The `cols` x `rows` section of the grid whose top left corner is `(x, y)`.
+    pub fn crop(&self, (x, y): Coord, cols: usize, rows: usize) -> Grid<T> where T: Clone {
+        assert!(x + cols <= self.cols && y + rows <= self.rows, "crop falls outside the grid");
+        let data = (y..y + rows).flat_map(|row| self.row(row)[x..x + cols].iter().cloned())
+                                .collect();
+        Grid::new(rows, cols, data)
+    }
+
+    /// Crops away every outer row and column made up solely of blank cells.
+    pub fn trim<F: Fn(&T) -> bool>(&self, is_blank: F) -> Grid<T> where T: Clone {
+        let occupied: Vec<Coord> = self.enumerate()
+                                       .filter(|(_, cell)| !is_blank(cell))
+                                       .map(|(coord, _)| coord)
+                                       .collect();
+        if occupied.is_empty() {
+            return Grid::new(0, 0, Vec::new());
+        }
+        let min_x = occupied.iter().map(|c| c.0).min().unwrap();
+        let max_x = occupied.iter().map(|c| c.0).max().unwrap();
+        let min_y = occupied.iter().map(|c| c.1).min().unwrap();
+        let max_y = occupied.iter().map(|c| c.1).max().unwrap();
+        self.crop((min_x, min_y), max_x - min_x + 1, max_y - min_y + 1)
+    }
+
+    /// The `size` x `size` window centred on `centre`, row by row.
+    pub fn kernel(&self, (x, y): Coord, size: usize) -> Vec<&T> {
+        assert!(!size.is_multiple_of(2), "kernel size must be odd");
+        assert!(x >= size / 2 && y >= size / 2, "kernel falls outside the grid");
+        (y - size / 2..=y + size / 2)
+            .flat_map(|cur_y| self.row(cur_y)[x - size / 2..=x + size / 2].iter())
+            .collect()
+    }
+
+    /// Every complete `size` x `size` kernel paired with the coordinate at its centre.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = (Coord, Vec<&T>)> {
+        let half = size / 2;
+        let (rows, cols) = (self.rows, self.cols);
+        self.coords()
+            .filter(move |&(x, y)| x >= half && y >= half && x + half < cols && y + half < rows)
+            .map(move |coord| (coord, self.kernel(coord, size)))
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.at(coord)
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.at_mut(coord)
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.iter_rows() {
+            writeln!(f, "{}", row.iter().map(Cell::to_char).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+/// Failure to parse a grid, with the 1-based line and column at fault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGridError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    /// Parses one row per non-blank line, one cell per character.
+    fn from_str(s: &str) -> Result<Grid<T>, ParseGridError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (i, line) in s.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let row = line.trim()
+                          .chars()
+                          .enumerate()
+                          .map(|(j, c)| T::from_char(c).ok_or_else(|| ParseGridError {
+                              line: i + 1,
+                              column: j + 1,
+                              message: format!("unexpected character {:?}", c),
+                          }))
+                          .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first() {
+                if first.len() != row.len() {
+                    return Err(ParseGridError {
+                        line: i + 1,
+                        column: row.len().min(first.len()) + 1,
+                        message: format!("expected {} cells, found {}", first.len(), row.len()),
+                    });
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+}
@@ -0,0 +1,176 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::grid::{Cell, Grid};
+
+pub use crate::error::Result;
+
+/// Parses a single token, reporting failure as an `InvalidToken` error.
+pub fn parse_token<T>(token: &str) -> Result<T>
+    where T: FromStr, T::Err: fmt::Display {
+    token.parse::<T>().map_err(|err| AocError::invalid_token(token, err))
+}
+
+/// Whitespace separated tokens of `line`, each with the 1-based column it starts at.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize + 1, token))
+}
+
+/// The trimmed pieces of `line` between each `separator`, each with the
+/// 1-based column it starts at.
+pub fn separated(line: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(separator)
+        .map(str::trim)
+        .map(move |token| (token.as_ptr() as usize - line.as_ptr() as usize + 1, token))
+}
+
+/// The path that stands for standard input, so any loader can read piped
+/// input, e.g. `cat input | day9 -`.
+pub const STDIN: &str = "-";
+
+/// The input file named on the command line: its only argument, or stdin
+/// when there is none. Returns a usage message if there are more.
+pub fn path_from_args() -> std::result::Result<PathBuf, String> {
+    let args: Vec<String> = env::args().collect();
+    match &args[..] {
+        [_] => Ok(PathBuf::from(STDIN)),
+        [_, path] => Ok(PathBuf::from(path)),
+        _ => Err(format!("usage: {} [INPUT]\nreads stdin when INPUT is - or missing",
+                         args.first().map_or("day", String::as_str))),
+    }
+}
+
+/// Every line read from `reader`, including blank ones. Errors are tagged
+/// with `path`, the name the input goes by.
+pub fn read_lines<R: Read>(reader: R, path: &Path) -> Result<Vec<String>> {
+    io::BufReader::new(reader).lines()
+                              .enumerate()
+                              .map(|(i, line)| line.map_err(|err| AocError::io(path, err).at_line(i + 1)))
+                              .collect()
+}
+
+/// Every line of the file, or of stdin if `path` is `-`, including blank ones.
+pub fn lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        return read_lines(io::stdin(), path);
+    }
+    let file = fs::File::open(path).map_err(|err| AocError::io(path, err))?;
+    read_lines(file, path)
+}
+
+/// Parses every non-blank line of the file as a `T`.
+pub fn parse_lines<T, P>(path: P) -> Result<Vec<T>>
+    where T: FromStr, T::Err: fmt::Display, P: AsRef<Path> {
+    parse_lines_with(path, |line| parse_token(line.trim()))
+}
+
+/// Parses every non-blank line of the file with `parse`, locating any error
+/// it returns on the line that caused it.
+pub fn parse_lines_with<T, P, F>(path: P, mut parse: F) -> Result<Vec<T>>
+    where P: AsRef<Path>, F: FnMut(&str) -> Result<T> {
+    let path = path.as_ref();
+    lines(path)?.iter()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| parse(line.trim_end()).map_err(|err| err.at_line(i + 1).in_file(path)))
+                .collect()
+}
+
+/// The contents of a file holding a single line of data, e.g. a hex string.
+pub fn single_line<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    let mut contents: Vec<_> = lines(path)?.into_iter()
+                                          .filter(|line| !line.trim().is_empty())
+                                          .collect();
+    match contents.len() {
+        1 => Ok(contents.remove(0).trim().to_string()),
+        0 => Err(AocError::unexpected_eof("a line of input").in_file(path)),
+        n => Err(AocError::format(format!("expected a single line of input, found {}", n)).in_file(path)),
+    }
+}
+
+/// Parses a single line of comma separated values, e.g. `3,4,3,1,2`.
+pub fn comma_separated<T, P>(path: P) -> Result<Vec<T>>
+    where T: FromStr, T::Err: fmt::Display, P: AsRef<Path> {
+    comma_separated_with(path, parse_token)
+}
+
+/// As `comma_separated`, parsing each value with `parse`.
+pub fn comma_separated_with<T, P, F>(path: P, mut parse: F) -> Result<Vec<T>>
+    where P: AsRef<Path>, F: FnMut(&str) -> Result<T> {
+    let path = path.as_ref();
+    let line = single_line(path)?;
+    separated(&line, ',').map(|(column, token)| parse(token).map_err(|err| err.at_token(column).at_line(1).in_file(path)))
+                         .collect()
+}
+
+/// A grid with one cell per character, e.g. digits or `#`/`.` pixels.
+pub fn grid<T: Cell, P: AsRef<Path>>(path: P) -> Result<Grid<T>> {
+    let path = path.as_ref();
+    lines(path)?.join("\n")
+                .parse()
+                .map_err(|err: crate::grid::ParseGridError|
+                         AocError::format(err.message).at_column(err.column).at_line(err.line).in_file(path))
+}
+
+/// Groups of non-blank lines separated by one or more blank lines, each
+/// line paired with its 1-based line number so errors can point at it.
+pub fn split_sections(lines: &[String]) -> Vec<Vec<(usize, &str)>> {
+    let mut sections = vec![Vec::new()];
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            if !sections.last().unwrap().is_empty() {
+                sections.push(Vec::new());
+            }
+        }
+        else {
+            sections.last_mut().unwrap().push((i + 1, line.trim_end()));
+        }
+    }
+    if sections.last().unwrap().is_empty() {
+        sections.pop();
+    }
+    sections
+}
+
+/// As `split_sections`, for the lines of a file.
+pub fn sections<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<(usize, String)>>> {
+    let lines = lines(path)?;
+    Ok(split_sections(&lines).into_iter()
+                             .map(|section| section.into_iter().map(|(number, line)| (number, line.to_string())).collect())
+                             .collect())
+}
+
+/// Splits a file of the form `header`, blank line, `body...`, as used by
+/// the polymer template and the image enhancement puzzles.
+pub fn header_and_body<P: AsRef<Path>>(path: P) -> Result<(String, Vec<String>)> {
+    header_and_body_with(path, |line| Ok(line.to_string()))
+}
+
+/// As `header_and_body`, parsing each non-blank body line with `parse` and
+/// locating any error it returns on the line that caused it.
+pub fn header_and_body_with<T, P, F>(path: P, mut parse: F) -> Result<(String, Vec<T>)>
+    where P: AsRef<Path>, F: FnMut(&str) -> Result<T> {
+    let path = path.as_ref();
+    let mut lines = lines(path)?.into_iter().enumerate();
+    let header = lines.next()
+                      .map(|(_, line)| line)
+                      .filter(|line| !line.trim().is_empty())
+                      .ok_or_else(|| AocError::format("missing header line").at_line(1).in_file(path))?;
+    match lines.next() {
+        Some((_, line)) if line.trim().is_empty() => (),
+        _ => return Err(AocError::format("expected a blank line after the header").at_line(2).in_file(path)),
+    }
+    let body = lines.filter(|(_, line)| !line.trim().is_empty())
+                    .map(|(i, line)| parse(line.trim_end()).map_err(|err| err.at_line(i + 1).in_file(path)))
+                    .collect::<Result<Vec<T>>>()?;
+    Ok((header, body))
+}
@@ -0,0 +1,13 @@
+//! Helpers shared between the daily puzzle crates.
+
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod solution;
+pub mod testing;
+
+pub use error::{AocError, ErrorKind, Result};
+pub use grid::Grid;
+pub use solution::{Answer, Part, Report, Solution, Solved};
@@ -0,0 +1,135 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(number: $int) -> Answer {
+                i64::try_from(number).map(Answer::Number)
+                                     .unwrap_or_else(|_| Answer::Text(number.to_string()))
+            }
+        })*
+    }
+}
+
+answer_from_int!(i32, i64, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Part, String> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, not {:?}", s)),
+        }
+    }
+}
+
+/// A day of the advent calendar: how to load its input and solve both parts.
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input;
+
+    fn parse(path: &Path) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+}
+
+/// One solved part of a puzzle and how long the solver took, parsing excluded.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Everything solved from one input file.
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// How long loading and parsing the input took.
+    pub parse_elapsed: Duration,
+    pub parts: Vec<Solved>,
+}
+
+/// Loads the input at `path` once and solves each of the requested parts.
+/// Errors from the solvers are tagged with `path`.
+pub fn run<S: Solution>(path: &Path, parts: &[Part]) -> Result<Report> {
+    let start = Instant::now();
+    let input = S::parse(path)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts.iter()
+                     .map(|&part| {
+                         let start = Instant::now();
+                         let answer = S::solve(&input, part).map_err(|err| err.in_file(path))?;
+                         Ok(Solved { part, answer, elapsed: start.elapsed() })
+                     })
+                     .collect::<Result<_>>()?;
+    Ok(Report { parse_elapsed, parts })
+}
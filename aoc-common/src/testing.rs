@@ -0,0 +1,84 @@
+//! Checks a day's solutions against the example inputs bundled with it.
+//!
+//! Each day directory holds an `expected_answers` manifest with one line per
+//! example input:
+//!
+//! ```text
+//! # input          part 1   part 2
+//! test_input       7        5
+//! ```
+//!
+//! A `-` leaves that part unchecked, and `\n` in an answer stands for a line
+//! break, for answers drawn as text.
+use std::fs;
+use std::path::Path;
+
+use crate::solution::{Part, Solution};
+
+pub const MANIFEST: &str = "expected_answers";
+
+/// One line of the manifest: an input file and the answers it should give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+fn parse_answer(token: &str) -> Option<String> {
+    match token {
+        "-" => None,
+        _ => Some(token.replace("\\n", "\n")),
+    }
+}
+
+/// Reads the manifest in `dir`, ignoring blank lines and `#` comments.
+pub fn read_manifest(dir: &Path) -> Vec<Example> {
+    let path = dir.join(MANIFEST);
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+
+    contents.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [input, part1, part2] => Example { input: input.to_string(),
+                                                   part1: parse_answer(part1),
+                                                   part2: parse_answer(part2) },
+                _ => panic!("{}:{}: expected an input file and two answers", path.display(), i + 1),
+            })
+            .collect()
+}
+
+/// Solves every example listed in the manifest in `dir` and panics with a
+/// summary of each wrong answer.
+pub fn check_examples<S: Solution>(dir: &str) {
+    let dir = Path::new(dir);
+    let examples = read_manifest(dir);
+    assert!(!examples.is_empty(), "no examples listed in {}", dir.join(MANIFEST).display());
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let input = S::parse(&dir.join(&example.input)).unwrap_or_else(|err| panic!("{}", err));
+        for part in Part::BOTH.iter().copied() {
+            if let Some(expected) = example.expected(part) {
+                let answer = S::solve(&input, part).unwrap_or_else(|err| panic!("{}: {}", example.input, err))
+                                                   .to_string();
+                if answer != expected {
+                    failures.push(format!("{} part {}: expected {:?}, got {:?}",
+                                          example.input, part, expected, answer));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "day {} gave wrong answers:\n{}", S::DAY, failures.join("\n"));
+}
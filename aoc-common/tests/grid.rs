@@ -0,0 +1,109 @@
+use aoc_common::grid::{Coord, ParseGridError};
+use aoc_common::Grid;
+
+const DIGITS: &str = "123\n456\n789\n";
+const PIXELS: &str = "#..#.\n.#...\n....#\n";
+
+fn digits() -> Grid<u32> {
+    DIGITS.parse().unwrap()
+}
+
+#[test]
+fn display_round_trips_through_parse() {
+    assert_eq!(digits().to_string(), DIGITS);
+    let pixels: Grid<bool> = PIXELS.parse().unwrap();
+    assert_eq!((pixels.rows(), pixels.cols()), (3, 5));
+    assert_eq!(pixels.to_string(), PIXELS);
+    assert_eq!(pixels.to_string().parse::<Grid<bool>>().unwrap(), pixels);
+}
+
+#[test]
+fn parse_skips_blank_lines_and_surrounding_whitespace() {
+    let grid: Grid<u32> = "\n  12\n\n34  \n".parse().unwrap();
+    assert_eq!(grid.data(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn parse_errors_locate_the_bad_cell() {
+    assert_eq!("12\n3x\n".parse::<Grid<u32>>().unwrap_err(),
+               ParseGridError { line: 2, column: 2, message: "unexpected character 'x'".to_string() });
+    let err = "123\n45\n".parse::<Grid<u32>>().unwrap_err();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.to_string(), "2:3: expected 3 cells, found 2");
+}
+
+#[test]
+fn wide_values_display_as_stars() {
+    let grid = Grid::from_rows(vec![vec![1, 12]]);
+    assert_eq!(grid.to_string(), "1*\n");
+}
+
+#[test]
+fn coordinates_are_column_then_row() {
+    let grid = digits();
+    assert_eq!(grid[(2, 0)], 3);
+    assert_eq!(grid[(0, 2)], 7);
+    assert_eq!(grid.index_of((1, 2)), 7);
+    assert_eq!(grid.coord_of(5), (2, 1));
+    assert_eq!(grid.get((-1, 0)), None);
+    assert_eq!(grid.get((1, 1)), Some(&5));
+    assert!(!grid.contains((3, 0)));
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5, 8]);
+}
+
+#[test]
+fn neighbours_stay_on_the_grid() {
+    let grid = digits();
+    let mut corner: Vec<Coord> = grid.neighbours4((0, 0)).collect();
+    corner.sort_unstable();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbours8((2, 1)).count(), 5);
+    assert_eq!(grid.neighbours8((1, 1)).map(|coord| grid[coord]).sum::<u32>(), 45 - 5);
+}
+
+#[test]
+fn pad_surrounds_the_grid() {
+    let padded = Grid::from_rows(vec![vec![true]]).pad(false, 1);
+    assert_eq!(padded.to_string(), "...\n.#.\n...\n");
+    assert_eq!(digits().pad(0, 2).rows(), 7);
+}
+
+#[test]
+fn crop_takes_a_section() {
+    assert_eq!(digits().crop((1, 1), 2, 2).to_string(), "56\n89\n");
+    assert_eq!(digits().crop((0, 0), 3, 1).to_string(), "123\n");
+}
+
+#[test]
+fn trim_drops_blank_edges() {
+    let pixels: Grid<bool> = "......\n..#...\n...#..\n......\n".parse().unwrap();
+    assert_eq!(pixels.trim(|&cell| !cell).to_string(), "#.\n.#\n");
+    let blank: Grid<bool> = "...\n...\n".parse().unwrap();
+    assert!(blank.trim(|&cell| !cell).is_empty());
+}
+
+#[test]
+fn kernel_reads_row_by_row() {
+    let grid = digits();
+    assert_eq!(grid.kernel((1, 1), 3).into_iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(grid.kernel((2, 0), 1), [&3]);
+}
+
+#[test]
+fn windows_cover_every_complete_kernel() {
+    let grid: Grid<u32> = "1234\n5678\n9012\n".parse().unwrap();
+    let windows: Vec<(Coord, Vec<u32>)> = grid.windows(3)
+                                              .map(|(coord, kernel)| (coord, kernel.into_iter().copied().collect()))
+                                              .collect();
+    assert_eq!(windows, [((1, 1), vec![1, 2, 3, 5, 6, 7, 9, 0, 1]), ((2, 1), vec![2, 3, 4, 6, 7, 8, 0, 1, 2])]);
+    assert_eq!(grid.windows(5).count(), 0);
+}
+
+#[test]
+fn map_keeps_the_shape() {
+    let odd = digits().map(|value| value % 2 == 1);
+    assert_eq!(odd.to_string(), "#.#\n.#.\n#.#\n");
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::{input, AocError, ErrorKind, Grid};
+
+/// Writes `contents` to a scratch file unique to this test run.
+fn scratch(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("aoc-common-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn parse_lines_errors_name_file_and_line() {
+    let path = scratch("parse_lines", "1\n\n2\nthree\n");
+    let err = input::parse_lines::<u32, _>(&path).unwrap_err();
+    assert_eq!(err.path.as_ref(), Some(&path));
+    assert_eq!(err.line, Some(4));
+}
+
+#[test]
+fn parse_lines_with_keeps_the_parser_column() {
+    let path = scratch("parse_lines_with", "1 2\n3 x\n");
+    let err = input::parse_lines_with(&path, |line| {
+        input::tokens(line).map(|(column, token)| input::parse_token::<u32>(token).map_err(|err| err.at_column(column)))
+                           .collect::<aoc_common::Result<Vec<_>>>()
+    }).unwrap_err();
+    assert_eq!(err.to_string(), format!("{}:2:3: invalid token \"x\": invalid digit found in string", path.display()));
+}
+
+#[test]
+fn comma_separated_errors_point_at_the_token() {
+    let path = scratch("comma_separated", "3,4, x,1\n");
+    let err = input::comma_separated::<u32, _>(&path).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(1), Some(6)));
+}
+
+#[test]
+fn single_line_rejects_extra_lines() {
+    let path = scratch("single_line", "abc\ndef\n");
+    assert_eq!(input::single_line(&path).unwrap_err().to_string(),
+               format!("{}: expected a single line of input, found 2", path.display()));
+}
+
+#[test]
+fn grid_errors_point_at_the_cell() {
+    let path = scratch("grid", "123\n4x6\n");
+    let err = input::grid::<u32, _>(&path).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(2), Some(2)));
+
+    let path = scratch("grid_ok", "12\n34\n");
+    let grid: Grid<u32> = input::grid(&path).unwrap();
+    assert_eq!((grid.rows(), grid.cols()), (2, 2));
+}
+
+#[test]
+fn sections_keep_their_line_numbers() {
+    let path = scratch("sections", "\na\nb\n\n\nc\n");
+    assert_eq!(input::sections(&path).unwrap(),
+               [vec![(2, "a".to_string()), (3, "b".to_string())], vec![(6, "c".to_string())]]);
+    assert!(input::split_sections(&[String::new()]).is_empty());
+}
+
+#[test]
+fn header_and_body_errors_are_located() {
+    let path = scratch("header_missing_blank", "NNCB\nCH -> B\n");
+    assert_eq!(input::header_and_body(&path).unwrap_err().line, Some(2));
+
+    let path = scratch("header_body", "NNCB\n\nCH -> B\nbad\n");
+    let err = input::header_and_body_with(&path, |line| match line.contains("->") {
+        true => Ok(line.to_string()),
+        false => Err(AocError::format("expected a rule")),
+    }).unwrap_err();
+    assert_eq!(err.line, Some(4));
+}
+
+#[test]
+fn missing_files_are_io_errors() {
+    let path = std::env::temp_dir().join("aoc-common-no-such-file");
+    let err = input::lines(&path).unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::Io(_)));
+    assert_eq!(err.path, Some(path));
+}
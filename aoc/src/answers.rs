@@ -0,0 +1,77 @@
+//! The answers store: the accepted answer to each part of each day, kept in
+//! a TOML file so `aoc verify` can catch a refactor that changes one.
+//!
+//! ```toml
+//! [day1]
+//! part1 = 1162
+//! part2 = 1190
+//! ```
+//!
+//! Numbers are stored as integers and text answers as strings. Recording
+//! edits the file in place, keeping any comments.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Answer, AocError, Part};
+use toml_edit::DocumentMut;
+
+/// Name of the store at the root of the workspace.
+pub const FILE_NAME: &str = "answers.toml";
+
+/// Where the store lives when no `--answers` is given.
+pub fn default_path() -> PathBuf {
+    crate::workspace_root().join(FILE_NAME)
+}
+
+pub struct Answers {
+    path: PathBuf,
+    doc: DocumentMut,
+}
+
+fn key(day: u32, part: Part) -> (String, String) {
+    (format!("day{}", day), format!("part{}", part))
+}
+
+impl Answers {
+    /// Reads the store at `path`, which starts out empty if there is no file yet.
+    pub fn load(path: &Path) -> aoc_common::Result<Answers> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(AocError::io(path, err)),
+        };
+        let doc = text.parse::<DocumentMut>().map_err(|err| AocError::format(err.message()).in_file(path))?;
+        Ok(Answers { path: path.to_path_buf(), doc })
+    }
+
+    /// The recorded answer, if there is one.
+    pub fn get(&self, day: u32, part: Part) -> aoc_common::Result<Option<Answer>> {
+        let (day_key, part_key) = key(day, part);
+        match self.doc.get(&day_key).and_then(|table| table.get(&part_key)) {
+            None => Ok(None),
+            Some(item) => match (item.as_integer(), item.as_str()) {
+                (Some(number), _) => Ok(Some(Answer::Number(number))),
+                (_, Some(text)) => Ok(Some(Answer::Text(text.to_string()))),
+                _ => Err(AocError::format(format!("{}.{} must be an integer or a string", day_key, part_key))
+                             .in_file(&self.path)),
+            },
+        }
+    }
+
+    pub fn record(&mut self, day: u32, part: Part, answer: &Answer) {
+        let (day_key, part_key) = key(day, part);
+        if !self.doc.contains_key(&day_key) {
+            self.doc.insert(&day_key, toml_edit::table());
+        }
+        self.doc[day_key.as_str()][part_key.as_str()] = match answer {
+            Answer::Number(number) => toml_edit::value(*number),
+            Answer::Text(text) => toml_edit::value(text.as_str()),
+        };
+    }
+
+    pub fn save(&self) -> aoc_common::Result<()> {
+        fs::write(&self.path, self.doc.to_string()).map_err(|err| AocError::io(&self.path, err))
+    }
+}
+
@@ -0,0 +1,10 @@
+//! Pieces of the `aoc` runner that are tested on their own.
+use std::path::Path;
+
+pub mod answers;
+
+/// The workspace directory, which holds every day's input and the answers
+/// store, so `aoc` finds them wherever it is run from.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("the aoc crate lives inside the workspace")
+}
@@ -0,0 +1,296 @@
+//! Runs any day of the calendar through its `Solution` implementation.
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Part, Report, Solution, Solved};
+
+use aoc::answers::{self, Answers};
+
+const USAGE: &str = "usage: aoc list
+       aoc run --day N [--part 1|2] [--input PATH|-] [--format text|json] [--time]
+       aoc run --all [--part 1|2] [--format text|json] [--time]
+       aoc verify --day N|--all [--part 1|2] [--record] [--answers PATH]";
+
+/// Loads an input file and solves the given parts of one day.
+type Runner = fn(&Path, &[Part]) -> aoc_common::Result<Report>;
+
+struct Day {
+    day: u32,
+    title: &'static str,
+    run: Runner,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day { day: S::DAY, title: S::TITLE, run: aoc_common::solution::run::<S> }
+    }
+}
+
+fn calendar() -> Vec<Day> {
+    vec![Day::of::<day1::Day1>(),
+         Day::of::<day2::Day2>(),
+         Day::of::<day3::Day3>(),
+         Day::of::<day4::Day4>(),
+         Day::of::<day5::Day5>(),
+         Day::of::<day6::Day6>(),
+         Day::of::<day7::Day7>(),
+         Day::of::<day8::Day8>(),
+         Day::of::<day9::Day9>(),
+         Day::of::<day10::Day10>(),
+         Day::of::<day11::Day11>(),
+         Day::of::<day12::Day12>(),
+         Day::of::<day13::Day13>(),
+         Day::of::<day14::Day14>(),
+         Day::of::<day15::Day15>(),
+         Day::of::<day16::Day16>(),
+         Day::of::<day17::Day17>(),
+         Day::of::<day18::Day18>(),
+         Day::of::<day19::Day19>(),
+         Day::of::<day20::Day20>(),
+         Day::of::<day21::Day21>()]
+}
+
+/// How answers are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// One `day N part P: answer` line per answer.
+    Text,
+    /// One `{"day", "part", "answer", "elapsed_ms"}` object per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("format must be text or json, not {:?}", s)),
+        }
+    }
+}
+
+struct RunOptions {
+    day: Option<u32>,
+    all: bool,
+    parts: Vec<Part>,
+    input: Option<PathBuf>,
+    format: Format,
+    time: bool,
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
+    let mut options = RunOptions {
+        day: None,
+        all: false,
+        parts: Part::BOTH.to_vec(),
+        input: None,
+        format: Format::Text,
+        time: false,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--day" => {
+                let day = value()?;
+                options.day = Some(day.parse().map_err(|_| format!("invalid day {:?}", day))?);
+            },
+            "--part" => options.parts = vec![value()?.parse()?],
+            "--input" => options.input = Some(PathBuf::from(value()?)),
+            "--format" => options.format = value()?.parse()?,
+            "--time" => options.time = true,
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+
+    match (options.all, options.day, &options.input) {
+        (true, Some(_), _) => Err("--all and --day cannot be combined".to_string()),
+        (true, None, Some(_)) => Err("--input needs a single --day".to_string()),
+        (false, None, _) => Err("either --day or --all is required".to_string()),
+        _ => Ok(options),
+    }
+}
+
+/// Where a day's puzzle input lives when no `--input` is given.
+fn default_input(day: u32) -> PathBuf {
+    aoc::workspace_root().join(format!("day{}", day)).join("input")
+}
+
+fn print_answer(day: u32, solved: &Solved, format: Format) {
+    match (format, &solved.answer) {
+        (Format::Text, Answer::Text(text)) if text.contains('\n') =>
+            println!("day {} part {}:\n{}", day, solved.part, text),
+        (Format::Text, answer) => println!("day {} part {}: {}", day, solved.part, answer),
+        (Format::Json, answer) => {
+            let answer = match answer {
+                Answer::Number(number) => serde_json::Value::from(*number),
+                Answer::Text(text) => serde_json::Value::from(text.as_str()),
+            };
+            let record = serde_json::json!({
+                "day": day,
+                "part": solved.part.number(),
+                "answer": answer,
+                "elapsed_ms": solved.elapsed.as_secs_f64() * 1000.0,
+            });
+            println!("{}", record);
+        },
+    }
+}
+
+/// Reports the wall time of each phase on stderr, leaving stdout to the answers.
+fn print_timings(day: u32, report: &Report) {
+    eprintln!("day {} parse took {:.2?}", day, report.parse_elapsed);
+    for solved in &report.parts {
+        eprintln!("day {} part {} took {:.2?}", day, solved.part, solved.elapsed);
+    }
+}
+
+/// Solves the requested parts of one day, reporting any failure on stderr.
+fn run_day(day: &Day, path: &Path, options: &RunOptions) -> bool {
+    match (day.run)(path, &options.parts) {
+        Ok(report) => {
+            report.parts.iter().for_each(|solved| print_answer(day.day, solved, options.format));
+            if options.time {
+                print_timings(day.day, &report);
+            }
+            true
+        },
+        Err(err) => {
+            eprintln!("error: day {}: {}", day.day, err);
+            false
+        },
+    }
+}
+
+/// The days selected by `options` and the input each is solved on. `--all`
+/// skips any day without an input, noting it on stderr.
+fn selected_days<'a>(calendar: &'a [Day], options: &RunOptions) -> Result<Vec<(&'a Day, PathBuf)>, String> {
+    if options.all {
+        return Ok(calendar.iter()
+                          .map(|day| (day, default_input(day.day)))
+                          .filter(|(day, path)| {
+                              if !path.exists() {
+                                  eprintln!("day {}: skipping, no input at {}", day.day, path.display());
+                              }
+                              path.exists()
+                          })
+                          .collect());
+    }
+
+    let number = options.day.unwrap();
+    let day = calendar.iter()
+                      .find(|day| day.day == number)
+                      .ok_or_else(|| format!("day {} has no solution", number))?;
+    Ok(vec![(day, options.input.clone().unwrap_or_else(|| default_input(number)))])
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    let options = parse_run_options(args)?;
+    let calendar = calendar();
+    Ok(selected_days(&calendar, &options)?.iter()
+                                          .fold(true, |ok, (day, path)| run_day(day, path, &options) && ok))
+}
+
+/// Solves one day and checks each answer against the store, recording those
+/// it has no answer for if `record` is set. Returns false on any mismatch or
+/// failure.
+fn verify_day(day: &Day, path: &Path, parts: &[Part], answers: &mut Answers, record: bool) -> bool {
+    let report = match (day.run)(path, parts) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error: day {}: {}", day.day, err);
+            return false;
+        },
+    };
+
+    let mut ok = true;
+    for solved in &report.parts {
+        let label = format!("day {} part {}", day.day, solved.part);
+        match answers.get(day.day, solved.part) {
+            Ok(Some(expected)) if expected == solved.answer => println!("{}: ok", label),
+            Ok(Some(expected)) => {
+                println!("{}: MISMATCH, expected {}, got {}", label, expected, solved.answer);
+                ok = false;
+            },
+            Ok(None) if record => {
+                answers.record(day.day, solved.part, &solved.answer);
+                println!("{}: recorded {}", label, solved.answer);
+            },
+            Ok(None) => println!("{}: unknown, got {}", label, solved.answer),
+            Err(err) => {
+                eprintln!("error: {}", err);
+                ok = false;
+            },
+        }
+    }
+    ok
+}
+
+fn verify(args: &[String]) -> Result<bool, String> {
+    let mut record = false;
+    let mut answers_path = answers::default_path();
+    let mut run_args = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--record" => record = true,
+            "--answers" => answers_path = PathBuf::from(args.next().ok_or("--answers needs a value")?),
+            "--input" | "--format" | "--time" => return Err(format!("{} only applies to aoc run", arg)),
+            _ => run_args.push(arg.clone()),
+        }
+    }
+    let options = parse_run_options(&run_args)?;
+
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return Ok(false);
+        },
+    };
+    let calendar = calendar();
+    let ok = selected_days(&calendar, &options)?
+        .iter()
+        .fold(true, |ok, (day, path)| verify_day(day, path, &options.parts, &mut answers, record) && ok);
+
+    if record {
+        if let Err(err) = answers.save() {
+            eprintln!("error: {}", err);
+            return Ok(false);
+        }
+    }
+    Ok(ok)
+}
+
+fn list() {
+    for day in calendar() {
+        println!("{:>2}  {}", day.day, day.title);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("list") => {
+            list();
+            Ok(true)
+        },
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        _ => Err("expected a subcommand".to_string()),
+    };
+
+    match result {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("error: {}\n{}", message, USAGE);
+            process::exit(2);
+        },
+    }
+}
@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::PathBuf;
+
+use aoc::answers::{self, Answers};
+use aoc_common::{Answer, Part};
+
+/// A path for a store unique to this test run, with `contents` written to
+/// it unless there are none.
+fn scratch(name: &str, contents: Option<&str>) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}-{}.toml", std::process::id(), name));
+    match contents {
+        Some(contents) => fs::write(&path, contents).unwrap(),
+        None => { let _ = fs::remove_file(&path); },
+    }
+    path
+}
+
+#[test]
+fn missing_stores_start_empty() {
+    let answers = Answers::load(&scratch("missing", None)).unwrap();
+    assert_eq!(answers.get(1, Part::One).unwrap(), None);
+}
+
+#[test]
+fn numbers_and_text_load_as_answers() {
+    let path = scratch("load", Some("[day1]\npart1 = 1162\npart2 = \"\"\"\n#.#\n.#.\"\"\"\n"));
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.get(1, Part::One).unwrap(), Some(Answer::Number(1162)));
+    assert_eq!(answers.get(1, Part::Two).unwrap(), Some(Answer::Text("#.#\n.#.".to_string())));
+    assert_eq!(answers.get(2, Part::One).unwrap(), None);
+}
+
+#[test]
+fn other_values_are_errors() {
+    let path = scratch("bad_value", Some("[day1]\npart1 = 1.5\n"));
+    let err = Answers::load(&path).unwrap().get(1, Part::One).unwrap_err();
+    assert_eq!(err.to_string(), format!("{}: day1.part1 must be an integer or a string", path.display()));
+
+    let path = scratch("bad_toml", Some("[day1\n"));
+    assert_eq!(Answers::load(&path).err().unwrap().path, Some(path));
+}
+
+#[test]
+fn recorded_answers_survive_a_save() {
+    let path = scratch("round_trip", None);
+    let mut answers = Answers::load(&path).unwrap();
+    answers.record(3, Part::One, &Answer::Number(4_001_724));
+    answers.record(13, Part::Two, &Answer::Text("#..#\n#..#".to_string()));
+    answers.save().unwrap();
+
+    let answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.get(3, Part::One).unwrap(), Some(Answer::Number(4_001_724)));
+    assert_eq!(answers.get(13, Part::Two).unwrap(), Some(Answer::Text("#..#\n#..#".to_string())));
+    assert!(fs::read_to_string(&path).unwrap().contains("[day3]\npart1 = 4001724\n"));
+}
+
+#[test]
+fn recording_keeps_comments_and_other_answers() {
+    let path = scratch("comments", Some("# accepted answers\n[day1]\npart1 = 1 # first try\npart2 = 2\n"));
+    let mut answers = Answers::load(&path).unwrap();
+    answers.record(1, Part::Two, &Answer::Number(3));
+    answers.save().unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "# accepted answers\n[day1]\npart1 = 1 # first try\npart2 = 3\n");
+}
+
+#[test]
+fn the_default_store_is_at_the_workspace_root() {
+    assert_eq!(answers::default_path(), aoc::workspace_root().join("answers.toml"));
+    assert!(answers::default_path().is_file());
+}
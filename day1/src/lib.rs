@@ -0,0 +1,270 @@
+//! Day 1: Sonar Sweep
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, ErrorKind, Result, Solution};
+
+pub struct Day1;
+
+/// How the values in a window are combined before windows are compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregator {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// The middle value, or the mean of the two middle values of an even window.
+    Median,
+}
+
+impl Aggregator {
+    pub fn aggregate(self, window: &[i32]) -> f64 {
+        let values = window.iter().map(|&value| value as f64);
+        match self {
+            Aggregator::Sum => values.sum(),
+            Aggregator::Mean => values.sum::<f64>() / window.len() as f64,
+            Aggregator::Min => values.fold(f64::INFINITY, f64::min),
+            Aggregator::Max => values.fold(f64::NEG_INFINITY, f64::max),
+            Aggregator::Median => {
+                let mut sorted = window.to_vec();
+                sorted.sort_unstable();
+                let middle = sorted.len() / 2;
+                if sorted.len() % 2 == 1 {
+                    sorted[middle] as f64
+                }
+                else {
+                    (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0
+                }
+            },
+        }
+    }
+}
+
+/// The sliding windows whose aggregate is larger than the window before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Increases {
+    pub count: usize,
+    /// The index of the first value of each window that increased.
+    pub indices: Vec<usize>,
+}
+
+/// Compares the aggregate of each `window` values with that of the window
+/// one value earlier. A window of 1 compares the values themselves.
+///
+/// Panics if `window` is 0.
+pub fn count_increases(values: &[i32], window: usize, aggregator: Aggregator) -> Increases {
+    assert!(window > 0, "the window must hold at least one value");
+    let aggregates: Vec<f64> = values.windows(window).map(|window| aggregator.aggregate(window)).collect();
+    let indices: Vec<usize> = aggregates.windows(2)
+                                        .enumerate()
+                                        .filter(|(_, pair)| pair[1] > pair[0])
+                                        .map(|(i, _)| i + 1)
+                                        .collect();
+    Increases { count: indices.len(), indices }
+}
+
+/// How a window compares with the window before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Increase,
+    Decrease,
+    Plateau,
+}
+
+/// Depth readings parsed one line at a time, so input of any length can be
+/// streamed. Blank lines are skipped and an unparsable line comes back as an
+/// error carrying its line number.
+pub fn readings<R: Read>(reader: R) -> impl Iterator<Item = Result<i32>> {
+    BufReader::new(reader).lines()
+                          .enumerate()
+                          .filter(|(_, line)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+                          .map(|(i, line)| {
+                              line.map_err(|err| AocError::new(ErrorKind::Io(err)))
+                                  .and_then(|line| input::parse_token(line.trim()))
+                                  .map_err(|err| err.at_line(i + 1))
+                          })
+}
+
+/// Iterator adapter comparing each window of readings with the one before as
+/// the readings arrive, keeping only the latest window in a ring buffer.
+///
+/// Yields the index of the first reading of each window after the first,
+/// as `count_increases` does, with how it changed. A reading that failed is
+/// passed on as an error and left out of the windows.
+pub struct DepthChanges<I> {
+    readings: I,
+    aggregator: Aggregator,
+    window: VecDeque<i32>,
+    size: usize,
+    previous: Option<f64>,
+    index: usize,
+}
+
+/// Compares sliding windows of `size` readings, aggregated by `aggregator`.
+///
+/// Panics if `size` is 0.
+pub fn depth_changes<I>(readings: I, size: usize, aggregator: Aggregator) -> DepthChanges<I::IntoIter>
+    where I: IntoIterator<Item = Result<i32>> {
+    assert!(size > 0, "the window must hold at least one value");
+    DepthChanges {
+        readings: readings.into_iter(),
+        aggregator,
+        window: VecDeque::with_capacity(size),
+        size,
+        previous: None,
+        index: 0,
+    }
+}
+
+impl<I: Iterator<Item = Result<i32>>> Iterator for DepthChanges<I> {
+    type Item = Result<(usize, Change)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let reading = match self.readings.next()? {
+                Ok(reading) => reading,
+                Err(err) => return Some(Err(err)),
+            };
+            if self.window.len() == self.size {
+                self.window.pop_front();
+                self.index += 1;
+            }
+            self.window.push_back(reading);
+            if self.window.len() < self.size {
+                continue;
+            }
+
+            let current = self.aggregator.aggregate(self.window.make_contiguous());
+            if let Some(previous) = self.previous.replace(current) {
+                let change = match current.partial_cmp(&previous) {
+                    Some(std::cmp::Ordering::Greater) => Change::Increase,
+                    Some(std::cmp::Ordering::Less) => Change::Decrease,
+                    _ => Change::Plateau,
+                };
+                return Some(Ok((self.index, change)));
+            }
+        }
+    }
+}
+
+/// Summary of how the depth changes over the whole report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthReport {
+    pub readings: usize,
+    pub increases: usize,
+    /// Most increases in a row.
+    pub longest_increase_run: usize,
+    /// The index of the reading after the biggest drop, and how far it
+    /// dropped. `None` if the depth never drops.
+    pub biggest_drop: Option<(usize, i64)>,
+    /// Indices of the readings shallower than both neighbours.
+    pub local_minima: Vec<usize>,
+    /// Indices of the readings deeper than both neighbours.
+    pub local_maxima: Vec<usize>,
+    /// How often each change between consecutive readings occurs.
+    pub delta_histogram: BTreeMap<i64, usize>,
+}
+
+/// Analyses the changes between consecutive readings.
+pub fn depth_report(values: &[i32]) -> DepthReport {
+    // in i64, as the difference between two i32 readings may not fit in one
+    let deltas: Vec<i64> = values.windows(2).map(|pair| i64::from(pair[1]) - i64::from(pair[0])).collect();
+
+    let (longest_increase_run, _) = deltas.iter().fold((0, 0), |(longest, run), &delta| {
+        let run = if delta > 0 { run + 1 } else { 0 };
+        (std::cmp::max(longest, run), run)
+    });
+    let biggest_drop = deltas.iter()
+                             .enumerate()
+                             .filter(|(_, &delta)| delta < 0)
+                             .min_by_key(|(i, &delta)| (delta, *i))
+                             .map(|(i, &delta)| (i + 1, -delta));
+    let turning_points = |turns: fn(i32, i32, i32) -> bool| -> Vec<usize> {
+        values.windows(3)
+              .enumerate()
+              .filter(|(_, triple)| turns(triple[0], triple[1], triple[2]))
+              .map(|(i, _)| i + 1)
+              .collect()
+    };
+
+    DepthReport {
+        readings: values.len(),
+        increases: deltas.iter().filter(|&&delta| delta > 0).count(),
+        longest_increase_run,
+        biggest_drop,
+        local_minima: turning_points(|before, value, after| value < before && value < after),
+        local_maxima: turning_points(|before, value, after| value > before && value > after),
+        delta_histogram: deltas.iter().fold(BTreeMap::new(), |mut histogram, &delta| {
+            *histogram.entry(delta).or_insert(0) += 1;
+            histogram
+        }),
+    }
+}
+
+impl fmt::Display for DepthReport {
+    /// Prints the report as a table, the histogram last.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indices = |indices: &[usize]| indices.iter().map(usize::to_string).collect::<Vec<_>>().join(" ");
+        writeln!(f, "{:<22} {}", "readings", self.readings)?;
+        writeln!(f, "{:<22} {}", "increases", self.increases)?;
+        writeln!(f, "{:<22} {}", "longest increase run", self.longest_increase_run)?;
+        match self.biggest_drop {
+            Some((index, drop)) => writeln!(f, "{:<22} {} at {}", "biggest drop", drop, index)?,
+            None => writeln!(f, "{:<22} -", "biggest drop")?,
+        }
+        writeln!(f, "{:<22} {}", "local minima", indices(&self.local_minima))?;
+        writeln!(f, "{:<22} {}", "local maxima", indices(&self.local_maxima))?;
+        writeln!(f, "{:>8}  count", "delta")?;
+        for (delta, count) in &self.delta_histogram {
+            writeln!(f, "{:>8}  {}", delta, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the values that are larger than the value before them.
+pub fn part_1(values : &[i32]) -> usize {
+    count_increases(values, 1, Aggregator::Sum).count
+}
+
+/// Counts the increases between the sums of each three-value sliding window.
+pub fn part_2(values : &[i32]) -> usize {
+    count_increases(values, 3, Aggregator::Sum).count
+}
+
+pub type Input = Vec<i32>;
+
+/// Reads the sonar sweep report, one depth measurement per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    input::parse_lines(path)
+}
+
+/// How many measurements are larger than the previous one.
+pub fn part1(values: &Input) -> Result<Answer> {
+    Ok(part_1(values).into())
+}
+
+/// How many sums of a three-measurement sliding window are larger than the previous sum.
+pub fn part2(values: &Input) -> Result<Answer> {
+    Ok(part_2(values).into())
+}
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -1,36 +1,76 @@
 use std::env;
-use std::fs;
-use std::io;
-use std::io::prelude::*;
-
-fn get_file_contents(filename : &String) -> Vec<i32>
-{
-    let file = fs::File::open(filename).expect("cannot open file");
-    io::BufReader::new(file).lines()
-                            .filter_map(|number| number.ok()?.parse::<i32>().ok())
-                            .collect()
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc_common::input;
+use day1::DepthReport;
+
+const USAGE: &str = "usage: day1 [--report table|json] [INPUT]
+reads stdin when INPUT is - or missing";
+
+/// What to print for the sonar sweep.
+enum Output {
+    Answers,
+    Table,
+    Json,
 }
 
-fn part_1(values : &Vec<i32>) {
-    let result = values.windows(2)
-                       .fold(0, |acc , x| acc + if x[1] > x[0] { 1 } else { 0 });
-    println!("{}", result);
+fn parse_args(args: &[String]) -> Result<(Output, PathBuf), String> {
+    let (output, rest) = match args {
+        [flag, format, rest @ ..] if flag == "--report" => match format.as_str() {
+            "table" => (Output::Table, rest),
+            "json" => (Output::Json, rest),
+            _ => return Err(format!("report format must be table or json, not {:?}", format)),
+        },
+        _ => (Output::Answers, args),
+    };
+    match rest {
+        [] => Ok((output, PathBuf::from(input::STDIN))),
+        [path] => Ok((output, PathBuf::from(path))),
+        _ => Err(format!("unexpected argument {:?}", rest[1])),
+    }
 }
 
-fn part_2(values : &Vec<i32>) {
-    let window_sums:Vec<i32> = values.windows(3)
-                                     .map(|x| x.iter().sum()).collect();
-    part_1(&window_sums);
+fn report_json(report: &DepthReport) -> serde_json::Value {
+    let histogram: Vec<_> = report.delta_histogram
+                                  .iter()
+                                  .map(|(delta, count)| serde_json::json!({ "delta": delta, "count": count }))
+                                  .collect();
+    serde_json::json!({
+        "readings": report.readings,
+        "increases": report.increases,
+        "longest_increase_run": report.longest_increase_run,
+        "biggest_drop": report.biggest_drop.map(|(index, drop)| serde_json::json!({ "index": index, "drop": drop })),
+        "local_minima": report.local_minima,
+        "local_maxima": report.local_maxima,
+        "delta_histogram": histogram,
+    })
+}
+
+fn run(output: Output, path: &Path) -> aoc_common::Result<()> {
+    let values = day1::parse(path)?;
+    match output {
+        Output::Answers => {
+            println!("Part 1");
+            println!("{}", day1::part1(&values)?);
+            println!("Part 2");
+            println!("{}", day1::part2(&values)?);
+        },
+        Output::Table => print!("{}", day1::depth_report(&values)),
+        Output::Json => println!("{}", report_json(&day1::depth_report(&values))),
+    }
+    Ok(())
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    assert_eq!(args.len(), 2, "Need to provide an input file as a second argument. \
-                               Number of arguments is not 2");
-    
-    let values = get_file_contents(&args[1]);
-    println!("Part 1");
-    part_1(&values); 
-    println!("Part 2");
-    part_2(&values);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (output, path) = parse_args(&args).unwrap_or_else(|message| {
+        eprintln!("error: {}\n{}", message, USAGE);
+        process::exit(2);
+    });
+
+    if let Err(err) = run(output, &path) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,46 @@
+use day1::{count_increases, depth_changes, readings, Aggregator, Change};
+
+const REPORT: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+#[test]
+fn changes_arrive_one_window_at_a_time() {
+    let changes: Vec<_> = depth_changes(readings(REPORT.as_bytes()), 3, Aggregator::Sum)
+        .collect::<aoc_common::Result<_>>()
+        .unwrap();
+    assert_eq!(changes, vec![(1, Change::Increase),
+                             (2, Change::Plateau),
+                             (3, Change::Decrease),
+                             (4, Change::Increase),
+                             (5, Change::Increase),
+                             (6, Change::Increase),
+                             (7, Change::Increase)]);
+}
+
+#[test]
+fn increases_match_count_increases() {
+    let values: Vec<i32> = readings(REPORT.as_bytes()).collect::<aoc_common::Result<_>>().unwrap();
+    for aggregator in [Aggregator::Sum, Aggregator::Mean, Aggregator::Min, Aggregator::Max, Aggregator::Median] {
+        for window in 1..=4 {
+            let increases: Vec<usize> = depth_changes(readings(REPORT.as_bytes()), window, aggregator)
+                .filter_map(|change| match change.unwrap() {
+                    (index, Change::Increase) => Some(index),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(increases, count_increases(&values, window, aggregator).indices);
+        }
+    }
+}
+
+#[test]
+fn unparsable_readings_are_errors_with_their_line() {
+    let mut changes = depth_changes(readings("1\n2\n\nthree\n4\n".as_bytes()), 1, Aggregator::Sum);
+    assert_eq!(changes.next().unwrap().unwrap(), (1, Change::Increase));
+
+    let err = changes.next().unwrap().unwrap_err();
+    assert_eq!(err.line, Some(4));
+    assert_eq!(err.to_string(), "4: invalid token \"three\": invalid digit found in string");
+
+    assert_eq!(changes.next().unwrap().unwrap(), (2, Change::Increase));
+    assert!(changes.next().is_none());
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day1::Day1;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day1>(env!("CARGO_MANIFEST_DIR"));
+}
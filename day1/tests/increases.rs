@@ -0,0 +1,37 @@
+use day1::{count_increases, Aggregator};
+
+const DEPTHS: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+#[test]
+fn single_values_are_compared_directly() {
+    let increases = count_increases(&DEPTHS, 1, Aggregator::Sum);
+    assert_eq!(increases.count, 7);
+    assert_eq!(increases.indices, vec![1, 2, 3, 5, 6, 7, 9]);
+}
+
+#[test]
+fn sums_of_three() {
+    let increases = count_increases(&DEPTHS, 3, Aggregator::Sum);
+    assert_eq!(increases.count, 5);
+    assert_eq!(increases.indices, vec![1, 4, 5, 6, 7]);
+}
+
+#[test]
+fn mean_orders_windows_like_sum() {
+    for window in 1..=DEPTHS.len() {
+        assert_eq!(count_increases(&DEPTHS, window, Aggregator::Mean),
+                   count_increases(&DEPTHS, window, Aggregator::Sum));
+    }
+}
+
+#[test]
+fn other_aggregators() {
+    assert_eq!(count_increases(&DEPTHS, 2, Aggregator::Min).indices, vec![1, 2, 5, 6, 7]);
+    assert_eq!(count_increases(&DEPTHS, 2, Aggregator::Max).indices, vec![1, 2, 5, 6]);
+    assert_eq!(count_increases(&DEPTHS, 4, Aggregator::Median).indices, vec![2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn windows_wider_than_the_report_never_increase() {
+    assert_eq!(count_increases(&DEPTHS, 11, Aggregator::Sum).count, 0);
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use day1::{depth_report, DepthReport};
+
+#[test]
+fn example_report() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(depth_report(&depths), DepthReport {
+        readings: 10,
+        increases: 7,
+        longest_increase_run: 3,
+        biggest_drop: Some((4, 10)),
+        local_minima: vec![4, 8],
+        local_maxima: vec![3, 7],
+        delta_histogram: [-10, -9, 1, 2, 3, 7, 8, 29, 33].iter().map(|&delta| (delta, 1)).collect::<BTreeMap<_, _>>(),
+    });
+}
+
+#[test]
+fn plateaus_are_not_turning_points() {
+    let report = depth_report(&[3, 1, 1, 3, 5, 5, 2]);
+    assert_eq!(report.local_minima, Vec::<usize>::new());
+    assert_eq!(report.local_maxima, Vec::<usize>::new());
+    assert_eq!(report.longest_increase_run, 2);
+    assert_eq!(report.biggest_drop, Some((6, 3)));
+    assert_eq!(report.delta_histogram.get(&0), Some(&2));
+}
+
+#[test]
+fn steady_ascent_never_drops() {
+    let report = depth_report(&[1, 2, 3]);
+    assert_eq!(report.biggest_drop, None);
+    assert_eq!(report.longest_increase_run, 2);
+}
+
+#[test]
+fn extreme_readings_do_not_overflow() {
+    let report = depth_report(&[i32::MIN, i32::MAX, i32::MIN]);
+    let span = i64::from(i32::MAX) - i64::from(i32::MIN);
+    assert_eq!(report.biggest_drop, Some((2, span)));
+    assert_eq!(report.delta_histogram, [(-span, 1), (span, 1)].iter().copied().collect::<BTreeMap<_, _>>());
+    assert_eq!(report.local_maxima, vec![1]);
+}
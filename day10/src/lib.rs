@@ -0,0 +1,124 @@
+//! Day 10: Syntax Scoring
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day10;
+
+/// What is wrong with a line of chunks, if anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineStatus {
+    /// Every chunk is closed.
+    Complete,
+    /// Some chunks are left open; holds the characters closing them, innermost first.
+    Incomplete(Vec<char>),
+    /// Holds the index of the first character closing the wrong chunk.
+    Corrupted(usize),
+}
+
+/// Checks the chunks of `line` are opened and closed in order.
+pub fn find_error_position(line : &str) -> Result<LineStatus> {
+    let mut closing_symbols = Vec::new();
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            '(' => closing_symbols.push(')'),
+            '{' => closing_symbols.push('}'),
+            '[' => closing_symbols.push(']'),
+            '<' => closing_symbols.push('>'),
+            ')' | '}' | ']' | '>' => if closing_symbols.pop() != Some(c) { 
+                                        return Ok(LineStatus::Corrupted(i)); 
+                                    },
+            _ => return Err(AocError::invalid_token(c, "expected a bracket").at_column(i + 1)),
+        }
+    }
+    if closing_symbols.is_empty() {
+        return Ok(LineStatus::Complete);
+    }
+    closing_symbols.reverse();
+    Ok(LineStatus::Incomplete(closing_symbols))
+}
+
+fn get_error_score(line : &str, idx : usize) -> u32 {
+    let error_char = line.as_bytes()[idx] as char;
+    match error_char {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        c => unreachable!("{} is not a closing symbol", c),
+    }
+}
+
+fn get_autocomplete_score(completing_symbols : &[char]) -> u64 {
+    completing_symbols.iter()
+                      .fold(0, |score, symbol|
+                                score * 5 + match symbol {
+                                    ')' => 1,
+                                    ']' => 2,
+                                    '}' => 3,
+                                    '>' => 4,
+                                    c => unreachable!("{} is not a closing symbol", c),
+                                })
+}
+
+/// Total syntax error score of the corrupted lines.
+pub fn error_score(lines : &[String]) -> Result<u32> {
+    lines.iter()
+         .try_fold(0, |score, line| {
+               match find_error_position(line)? {
+                   LineStatus::Corrupted(idx) => Ok(score + get_error_score(line, idx)),
+                   _ => Ok(score),
+               }
+         })
+}
+
+/// Middle autocomplete score of the incomplete lines.
+pub fn middle_autocomplete_score(lines : &[String]) -> Result<u64> {
+    let mut autocomplete_scores : Vec<u64> = Vec::new();
+    for line in lines {
+        if let LineStatus::Incomplete(complete_symbols) = find_error_position(line)? {
+            autocomplete_scores.push(get_autocomplete_score(&complete_symbols));
+        }
+    }
+
+    if autocomplete_scores.is_empty() {
+        return Err(AocError::no_solution("no line is incomplete"));
+    }
+    autocomplete_scores.sort();
+    Ok(autocomplete_scores[autocomplete_scores.len()/2])
+}
+
+pub type Input = Vec<String>;
+
+/// Reads the navigation subsystem, one chunk line per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    input::parse_lines_with(path, |line| find_error_position(line).map(|_| line.to_string()))
+}
+
+/// Total syntax error score of the corrupted lines.
+pub fn part1(lines: &Input) -> Result<Answer> {
+    Ok(error_score(lines)?.into())
+}
+
+/// Middle autocomplete score of the incomplete lines.
+pub fn part2(lines: &Input) -> Result<Answer> {
+    Ok(middle_autocomplete_score(lines)?.into())
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day10::Day10;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day10>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,64 @@
+use day10::LineStatus;
+use proptest::prelude::*;
+
+const OPENING: [char; 4] = ['(', '[', '{', '<'];
+const CLOSING: [char; 4] = [')', ']', '}', '>'];
+const ERROR_SCORES: [u32; 4] = [3, 57, 1197, 25137];
+
+/// A line built from random steps, each opening a chunk of the given kind or
+/// closing the innermost open chunk. Returns the line and the characters
+/// that would close the chunks still open, innermost first.
+fn build_line(steps: &[Option<usize>]) -> (String, Vec<char>) {
+    let mut line = String::new();
+    let mut open = Vec::new();
+    for step in steps {
+        match step {
+            Some(kind) => {
+                line.push(OPENING[*kind]);
+                open.push(CLOSING[*kind]);
+            },
+            None => if let Some(closing) = open.pop() {
+                line.push(closing);
+            },
+        }
+    }
+    open.reverse();
+    (line, open)
+}
+
+fn steps() -> impl Strategy<Value = Vec<Option<usize>>> {
+    prop::collection::vec(prop::option::of(0..4_usize), 0..40)
+}
+
+proptest! {
+    #[test]
+    fn complete_lines_have_no_error(steps in steps()) {
+        let (prefix, closing) = build_line(&steps);
+        let line: String = prefix.chars().chain(closing).collect();
+        prop_assume!(!line.is_empty());
+
+        prop_assert_eq!(day10::find_error_position(&line).unwrap(), LineStatus::Complete);
+        prop_assert_eq!(day10::error_score(&[line]).unwrap(), 0);
+    }
+
+    #[test]
+    fn incomplete_lines_report_their_completion(steps in steps()) {
+        let (line, closing) = build_line(&steps);
+        prop_assume!(!closing.is_empty());
+
+        prop_assert_eq!(day10::find_error_position(&line).unwrap(), LineStatus::Incomplete(closing));
+        prop_assert_eq!(day10::error_score(&[line]).unwrap(), 0);
+    }
+
+    #[test]
+    fn corrupted_lines_stop_at_the_first_illegal_character(steps in steps(),
+                                                           kind in 0..4_usize,
+                                                           tail in "[\\[\\](){}<>]{0,10}") {
+        let (prefix, closing) = build_line(&steps);
+        prop_assume!(closing.first() != Some(&CLOSING[kind]));
+        let line = format!("{}{}{}", prefix, CLOSING[kind], tail);
+
+        prop_assert_eq!(day10::find_error_position(&line).unwrap(), LineStatus::Corrupted(prefix.len()));
+        prop_assert_eq!(day10::error_score(&[line]).unwrap(), ERROR_SCORES[kind]);
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day11::Day11;
+
+fn solution(c: &mut Criterion) {
+    bench::bench_solution::<Day11>(c, env!("CARGO_MANIFEST_DIR"));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
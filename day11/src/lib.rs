@@ -0,0 +1,106 @@
+//! Day 11: Dumbo Octopus
+use std::path::Path;
+use aoc_common::{input, Answer, Result, Solution};
+use aoc_common::grid::{Coord, Grid};
+
+pub struct Day11;
+
+/// Runs a single step, returning the new grid and how many octopuses flashed.
+pub fn run_step(grid : &Grid<u32>) -> (Grid<u32>, u32) {
+    let mut has_flashed : Vec<Coord> = Vec::new();
+    let mut update = grid.map(|x| x + 1);
+
+    loop {
+        let mut at_peak_energy : Vec<Coord> = 
+            update
+                .enumerate()
+                .filter_map(|(coord, energy)| { 
+                            if energy > &9 && !has_flashed.contains(&coord) {
+                                return Some(coord);
+                            }
+                            None
+                            })
+                .collect();
+
+        if at_peak_energy.is_empty() {
+            break;
+        }
+
+        at_peak_energy
+            .iter()
+            .for_each(|&coord| 
+                      grid.neighbours8(coord)
+                          .for_each(|adj_coord| update[adj_coord] += 1));
+        has_flashed.append(&mut at_peak_energy);
+    } 
+
+    has_flashed.iter().for_each(|&point| update[point] = 0);
+
+    (update, has_flashed.len() as u32)
+}
+
+/// Total flashes over `steps` steps.
+pub fn total_flashes(grid : &Grid<u32>, steps : u32) -> u32 {
+    let mut update = grid.clone();
+    let mut total_flashes = 0;
+    for _ in 0..steps {
+        let result = run_step(&update);
+        update = result.0;
+        let has_flashed = result.1;
+        //println!("flashed: {}\nupdate:\n{}", has_flashed, update);
+        total_flashes += has_flashed
+    }
+    total_flashes
+}
+
+/// First step in which every octopus flashes.
+pub fn first_synchronised_step(grid : &Grid<u32>) -> u32 {
+    let mut update = grid.clone();
+    let mut step = 0;
+    loop { 
+        step += 1;
+        let result = run_step(&update);
+        update = result.0;
+        let has_flashed = result.1;
+        if has_flashed == grid.len() as u32 {
+            break;
+        }
+    }
+    step
+}
+
+pub type Input = Grid<u32>;
+
+/// Reads the octopus energy levels.
+pub fn parse(path: &Path) -> Result<Input> {
+    input::grid(path)
+}
+
+/// Total flashes after 100 steps.
+pub fn part1(grid: &Input) -> Result<Answer> {
+    Ok(total_flashes(grid, 100).into())
+}
+
+/// First step during which every octopus flashes.
+pub fn part2(grid: &Input) -> Result<Answer> {
+    Ok(first_synchronised_step(grid).into())
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day11::Day11;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day11>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,122 @@
+//! Day 12: Passage Pathing
+use std::path::Path;
+use std::collections::HashMap;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day12;
+
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn parse_connection(line : &str) -> Result<(String, String)> {
+    let (cave, conn) = line.trim()
+                           .split_once('-')
+                           .ok_or_else(|| AocError::format("expected a connection such as start-A"))?;
+    if !is_small_cave(cave) && !is_small_cave(conn) {
+        // there would be infinitely many paths bouncing between the two
+        return Err(AocError::format("two big caves cannot be connected"));
+    }
+    Ok((cave.to_string(), conn.to_string()))
+}
+
+fn build_graph_from_file(filename : &Path) -> Result<Graph> {
+    let graph = input::parse_lines_with(filename, parse_connection)?
+        .into_iter()
+        .fold(HashMap::new(), |mut graph : Graph, (cave, conn)| {
+            graph.entry(cave.clone()).or_default().push(conn.clone());
+            graph.entry(conn).or_default().push(cave);
+            graph
+        });
+
+    match ["start", "end"].iter().find(|cave| !graph.contains_key(**cave)) {
+        Some(missing) => Err(AocError::format(format!("no connection to the {} cave", missing)).in_file(filename)),
+        None => Ok(graph),
+    }
+}
+
+fn is_small_cave(cave : &str) -> bool {
+    cave.chars().any(char::is_lowercase)
+}
+
+fn traverse(
+    graph : &Graph, 
+    cave : &String, 
+    mut path : Vec<String>,
+    predicate : fn(&str, &[String]) -> bool) -> Vec<Vec<String>> { 
+
+    path.push(cave.clone());
+    if cave == "end" {
+        return vec![path];
+    }
+
+    graph[cave]
+        .iter()
+        .fold(Vec::new(), |mut paths, conn| { 
+            if predicate(conn, &path) {
+                paths.append(&mut traverse(graph, conn, path.clone(), predicate))
+            }
+            paths
+        })
+}
+
+/// Every path from `start` to `end` allowed by `predicate`.
+pub fn find_all_paths(graph : &Graph, predicate : fn(&str, &[String]) -> bool) -> Vec<Vec<String>> {
+    traverse(graph, &"start".to_string(), Vec::new(), predicate)
+}
+
+fn part1_predicate(conn : &str, path : &[String]) -> bool {
+    !(is_small_cave(conn) && path.iter().any(|cave| cave == conn))
+}
+
+fn part2_predicate(conn : &str, path : &[String]) -> bool {
+    if conn == "start" {
+        return false;
+    }
+    else if conn == "end" {
+        return true;
+    }
+    if is_small_cave(conn) {
+        let mut hist : HashMap<&str, u32>  = HashMap::new();
+        hist.insert(conn, 1);
+        for cave in path.iter().filter(|&cave| is_small_cave(cave)) {
+            *hist.entry(cave.as_str()).or_insert(0) += 1;
+        }
+        return hist.into_values().filter(|&c| c > 1).sum::<u32>() <= 2;
+    }
+    true
+}
+
+pub type Input = Graph;
+
+/// Reads the cave connections into an undirected graph.
+pub fn parse(path: &Path) -> Result<Input> {
+    build_graph_from_file(path)
+}
+
+/// Number of paths from `start` to `end` visiting small caves at most once.
+pub fn part1(graph: &Input) -> Result<Answer> {
+    Ok(find_all_paths(graph, part1_predicate).len().into())
+}
+
+/// Number of paths when a single small cave may be visited twice.
+pub fn part2(graph: &Input) -> Result<Answer> {
+    Ok(find_all_paths(graph, part2_predicate).len().into())
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
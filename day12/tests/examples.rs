@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day12::Day12;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day12>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,128 @@
+//! Day 13: Transparent Origami
+use std::path::Path;
+use std::collections::HashSet;
+use regex::Regex;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day13;
+
+pub type Dot = (u32, u32);
+pub type Line = (char, u32);
+
+fn parse_dot(line : &str) -> Result<Dot> {
+    let coords = input::separated(line, ',')
+        .map(|(column, token)| input::parse_token(token).map_err(|err| err.at_column(column)))
+        .collect::<Result<Vec<u32>>>()?;
+    match coords[..] {
+        [x, y] => Ok((x, y)),
+        _ => Err(AocError::format("expected a dot such as 6,10 or a fold such as fold along y=7")),
+    }
+}
+
+fn get_file_contents(filename : &Path) -> Result<(HashSet<Dot>, Vec<Line>)> {
+    let mut dots = HashSet::new();
+    let mut lines = Vec::new();
+    let line_regex = Regex::new(r"^fold along ([xy])=([0-9]+)$").unwrap();
+
+    for (i, line) in input::lines(filename)?.iter().enumerate() {
+        let locate = |err: AocError| err.at_line(i + 1).in_file(filename);
+        if line.trim().is_empty() {
+            continue;
+        }
+        else if let Some(cap) = line_regex.captures(line.trim()) {
+            let position = cap.get(2).unwrap();
+            lines.push((cap[1].chars().next().unwrap(),
+                        input::parse_token(position.as_str()).map_err(|err| locate(err.at_column(position.start() + 1)))?));
+        }
+        else {
+            dots.insert(parse_dot(line).map_err(locate)?);
+        }
+    }
+
+    Ok((dots, lines))
+}
+
+/// The dots left after folding along `line`.
+pub fn do_fold(line : &Line, dots : &HashSet<Dot>) -> Result<HashSet<Dot>> {
+    let fold_x = |dot : &Dot| {
+            if dot.0 > line.1 {
+                let new_x = line.1.checked_sub(dot.0 - line.1)?;
+                return Some((new_x, dot.1));
+            }
+            Some((dot.0, dot.1))
+        };
+
+    let fold_y = |dot : &Dot| {
+            if dot.1 > line.1 {
+                let new_y = line.1.checked_sub(dot.1 - line.1)?;
+                return Some((dot.0, new_y));
+            }
+            Some((dot.0, dot.1))
+        };
+
+    let fold = |dot : &Dot| if line.0 == 'x' { fold_x(dot) } else { fold_y(dot) };
+
+    dots.iter()
+        .map(|dot| fold(dot).ok_or_else(||
+            AocError::no_solution(format!("folding along {}={} moves {:?} off the paper", line.0, line.1, dot))))
+        .collect()
+}
+
+/// Draws the dots as rows of `#` and `.`.
+pub fn render(dots : &HashSet<Dot>) -> String {
+    let (max_x, max_y) = match (dots.iter().map(|dot| dot.0).max(), dots.iter().map(|dot| dot.1).max()) {
+        (Some(max_x), Some(max_y)) => (max_x, max_y),
+        _ => return String::new(),
+    };
+
+    let mut out = String::new();
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            if dots.contains(&(x,y)) {
+                out.push('#');
+            }
+            else {
+                out.push('.');
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub type Input = (HashSet<Dot>, Vec<Line>);
+
+/// Reads the dots and the fold instructions.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Number of dots visible after the first fold.
+pub fn part1((dots, lines): &Input) -> Result<Answer> {
+    let first = lines.first().ok_or_else(|| AocError::no_solution("there are no folds"))?;
+    Ok(do_fold(first, dots)?.len().into())
+}
+
+/// The code revealed by completing every fold, rendered as `#` and `.` rows.
+pub fn part2((dots, lines): &Input) -> Result<Answer> {
+    Ok(render(&lines.iter().try_fold(dots.clone(), |dots, line| do_fold(line, &dots))?).into())
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
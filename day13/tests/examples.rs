@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day13::Day13;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day13>(env!("CARGO_MANIFEST_DIR"));
+}
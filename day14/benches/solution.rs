@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day14::Day14;
+
+fn solution(c: &mut Criterion) {
+    bench::bench_solution::<Day14>(c, env!("CARGO_MANIFEST_DIR"));
+}
+
+/// The three ways of counting elements, over the 10 steps of part 1.
+fn approaches(c: &mut Criterion) {
+    let (start, map) = day14::parse(&Path::new(env!("CARGO_MANIFEST_DIR")).join(bench::INPUT)).unwrap();
+
+    let mut group = c.benchmark_group("day14/approaches");
+    group.bench_function("naiive", |b| b.iter(|| day14::naiive_approach(&start, &map, 10)));
+    group.bench_function("recursive", |b| b.iter(|| day14::get_solution_recursive(&start, &map, 10)));
+    group.bench_function("pair counts", |b| b.iter(|| day14::get_solution(&start, &map, 10)));
+    group.finish();
+}
+
+criterion_group!(benches, solution, approaches);
+criterion_main!(benches);
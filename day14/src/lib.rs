@@ -0,0 +1,162 @@
+//! Day 14: Extended Polymerization
+use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day14;
+
+pub type Pair = (char, char);
+pub type SeqMap = HashMap<Pair, char>;
+type CreationMap = HashMap<Pair, (Pair, Pair)>;
+
+fn parse_rule(line : &str) -> Result<(Pair, char)> {
+    let (pair, insertion) = line.split_once(" -> ")
+                                .ok_or_else(|| AocError::format("expected a rule such as CH -> B"))?;
+    let pair = pair.chars()
+                   .collect_tuple()
+                   .ok_or_else(|| AocError::invalid_token(pair, "expected a pair of elements").at_column(1))?;
+    let insertion = insertion.chars()
+                             .exactly_one()
+                             .map_err(|_| AocError::invalid_token(insertion, "expected a single element")
+                                              .at_column(line.len() - insertion.len() + 1))?;
+    Ok((pair, insertion))
+}
+
+fn get_file_contents(filename : &Path) -> Result<(Vec<char>, SeqMap)> {
+    let (template, rules) = input::header_and_body_with(filename, parse_rule)?;
+
+    let start : Vec<_> = template.trim().chars().collect();
+    let map : SeqMap = rules.into_iter().collect();
+
+    // every pair that can ever form needs a rule, or the polymer cannot grow
+    let elements : HashSet<char> = start.iter().copied()
+                                        .chain(map.iter().flat_map(|(pair, c)| vec![pair.0, pair.1, *c]))
+                                        .collect();
+    if let Some(pair) = elements.iter().cartesian_product(&elements).find(|(a, b)| !map.contains_key(&(**a, **b))) {
+        return Err(AocError::format(format!("no insertion rule for {}{}", pair.0, pair.1)).in_file(filename));
+    }
+
+    Ok((start, map))
+}
+
+fn make_creation_map(seq_map: &SeqMap) -> CreationMap {
+    seq_map.iter().map(|(pair, letter)| (*pair, ((pair.0, *letter), (*letter, pair.1)))).collect()
+}
+
+fn step(sequence : &[char], map : &SeqMap) -> Vec<char> {
+    let mut res = sequence
+        .windows(2)
+        .fold(Vec::new(), |mut seq, pair| {
+            seq.push(pair[0]);
+            seq.push(map[&(pair[0], pair[1])]);
+            seq
+        });
+    res.push(*sequence.last().unwrap());
+    res
+}
+
+/// Builds the polymer step by step. Only feasible for a few steps.
+pub fn naiive_approach(start: &[char], map: &SeqMap, count :u32) -> u32 {
+    let mut seq = start.to_vec();
+    for _ in 0..count {
+        seq = step(&seq, map);
+    }
+
+    let hist = seq.iter().fold(HashMap::new(), |mut hist, el| {
+        *hist.entry(el).or_insert(0) += 1;
+        hist
+    });
+
+    let max = hist.values().max().unwrap();
+    let min = hist.values().min().unwrap();
+
+    max - min
+}
+
+fn get_counts(seq: &[char], map: &SeqMap, hist: &mut HashMap<char, u32>, count: u32) {
+    if count == 0 {
+        return;
+    }
+
+    seq.windows(2).for_each(|section| {
+        let new_el = map[&(section[0], section[1])];
+        *hist.entry(new_el).or_insert(0) += 1;
+        get_counts(&[section[0], new_el, section[1]], map, hist, count - 1)
+    })
+}
+
+/// Counts the elements by recursing into each pair, without building the polymer.
+pub fn get_solution_recursive(seq: &[char], map: &SeqMap, count: u32) -> u32 {
+    let mut hist = seq.iter().fold(HashMap::new(), |mut hist, c| {
+        *hist.entry(*c).or_insert(0) += 1;
+        hist
+    });
+    get_counts(seq, map, &mut hist, count);
+    let min = hist.values().min().unwrap();
+    let max = hist.values().max().unwrap();
+    max - min
+}
+
+/// Counts the elements by tracking pair counts, which scales to many steps.
+pub fn get_solution(seq: &[char], map: &SeqMap, count: u32) -> u64 {
+    let creation_map = make_creation_map(map);
+    let mut letter_hist = seq.iter().fold(HashMap::new(), |mut hist, c| {
+        *hist.entry(*c).or_insert(0_u64) += 1;
+        hist
+    });
+
+    let mut pair_hist = seq.windows(2).fold(HashMap::new(), |mut hist, pair| {
+        *hist.entry((pair[0], pair[1])).or_insert(0_u64) += 1;
+        hist
+    });
+
+    for _ in 0..count {
+        pair_hist = pair_hist.iter().fold(HashMap::new(), |mut hist, (pair, count)| {
+            let created_pair = creation_map[pair];
+            *letter_hist.entry(map[pair]).or_insert(0) += count;
+            *hist.entry(created_pair.0).or_insert(0) += count;
+            *hist.entry(created_pair.1).or_insert(0) += count;
+            hist
+        });
+    }
+    let min = letter_hist.values().min().unwrap();
+    let max = letter_hist.values().max().unwrap();
+    max - min
+}
+
+pub type Input = (Vec<char>, SeqMap);
+
+/// Reads the polymer template and the pair insertion rules.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Most common minus least common element after 10 steps.
+pub fn part1((start, map): &Input) -> Result<Answer> {
+    Ok(get_solution_recursive(start, map, 10).into())
+}
+
+/// Most common minus least common element after 40 steps.
+pub fn part2((start, map): &Input) -> Result<Answer> {
+    Ok(get_solution(start, map, 40).into())
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day14::Day14;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day14>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,26 @@
+use day14::SeqMap;
+use proptest::prelude::*;
+
+const ELEMENTS: [char; 4] = ['B', 'C', 'H', 'N'];
+
+/// A template drawn from a few of the elements, with an insertion rule for
+/// every pair of them, as the puzzle guarantees.
+fn polymer() -> impl Strategy<Value = (Vec<char>, SeqMap)> {
+    (1..=ELEMENTS.len()).prop_flat_map(|count| {
+        let elements = &ELEMENTS[..count];
+        let pairs: Vec<_> = elements.iter().flat_map(|a| elements.iter().map(move |b| (*a, *b))).collect();
+        let element = prop::sample::select(elements);
+        (prop::collection::vec(element.clone(), 1..8),
+         prop::collection::vec(element, pairs.len()).prop_map(move |insertions|
+             pairs.iter().copied().zip(insertions).collect()))
+    })
+}
+
+proptest! {
+    #[test]
+    fn all_approaches_agree((template, rules) in polymer(), steps in 0..6_u32) {
+        let naiive = day14::naiive_approach(&template, &rules, steps);
+        prop_assert_eq!(day14::get_solution_recursive(&template, &rules, steps), naiive);
+        prop_assert_eq!(day14::get_solution(&template, &rules, steps), naiive as u64);
+    }
+}
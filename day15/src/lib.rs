@@ -0,0 +1,133 @@
+//! Day 15: Chiton
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+use aoc_common::grid::{Coord, Grid};
+
+pub struct Day15;
+
+#[derive(Clone)]
+struct Node {
+    fscore: u32,
+    gscore: u32,
+    prev: Option<Coord>,
+    visited: bool
+}
+
+fn distance(from: &Coord, to: &Coord) -> u32 {
+    (to.0 - from.0) as u32 + (to.1 - from.1) as u32
+}
+
+/// Lowest total risk of moving from `source` to `target`.
+pub fn dijkstra(risk_map: &Grid<u32>, source: &Coord, target: &Coord) -> u32 {  
+    let mut score_map = Grid::filled(risk_map.rows(),
+                                     risk_map.cols(),
+                                     Node{gscore: u32::MAX, fscore: u32::MAX, prev: None, visited: false});
+
+    score_map[*source].fscore = 0;
+    score_map[*source].gscore = 0;
+
+    while !score_map[*target].visited {
+        let lowest_score_idx = score_map.iter()
+                                        .enumerate()
+                                        .filter(|(_, node)| !node.visited)
+                                        .min_by_key(|(_, node)| node.fscore)
+                                        .map(|(i,_)| i)
+                                        .unwrap();
+
+        let coord = score_map.coord_of(lowest_score_idx);
+        let node = &mut score_map[coord];
+        node.visited = true;
+        let score = node.gscore;
+
+        risk_map.neighbours4(coord).for_each(|neighbour| {
+            let score_to_neighbour = score + risk_map[neighbour];
+            //println!("Score from {:?} -> {:?}: {}", node_coord, neighbour, score_to_neighbour);
+            let neighbour_node = &mut score_map[neighbour];
+            if score_to_neighbour < neighbour_node.gscore {
+                neighbour_node.gscore = score_to_neighbour;
+                neighbour_node.fscore = score_to_neighbour + distance(&neighbour, target);
+                neighbour_node.prev = Some(coord);
+            }
+        });
+    }
+
+    //let mut node = target.clone();
+    //let mut score = 0;
+    //while &node != source {
+    //    score += risk_map[node];
+    //    node = score_map[node].prev.unwrap();
+    //}
+    //return score;
+    score_map[*target].gscore
+}
+
+/// The full map: five tiles in each direction, risk increasing with each tile.
+pub fn expand_map(risk_map: &Grid<u32>) -> Grid<u32> {
+    let expanded_cols : Vec<u32> = risk_map.iter_rows()
+                                           .map(|row| 
+                                                (0..5).map(|inc| 
+                                                           row.iter()
+                                                              .map(|i| i + inc ).collect())
+                                                      .collect::<Vec<Vec<u32>>>().concat())
+                                           .collect::<Vec<Vec<u32>>>()
+                                           .concat();
+
+    let expanded : Vec<u32> = (0..5).map(|inc| 
+                                         expanded_cols.iter()
+                                                      .map(|i| (i + inc - 1) % 9 + 1).collect())
+                                    .collect::<Vec<Vec<u32>>>()
+                                    .concat();
+
+    Grid::new(risk_map.rows() * 5, risk_map.cols() * 5, expanded)
+}
+
+pub type Input = Grid<u32>;
+
+/// Checks the map has at least one cell and that every risk level is 1 to
+/// 9, which the search and the tiling in `expand_map` rely on.
+pub fn check_risk_map(risk_map: &Grid<u32>) -> Result<()> {
+    if risk_map.is_empty() {
+        return Err(AocError::unexpected_eof("a map of risk levels"));
+    }
+    match risk_map.enumerate().find(|(_, &risk)| !(1..=9).contains(&risk)) {
+        Some(((x, y), risk)) => Err(AocError::invalid_token(risk, "risk levels run from 1 to 9").at_column(x + 1).at_line(y + 1)),
+        None => Ok(()),
+    }
+}
+
+/// Reads the risk level map.
+pub fn parse(path: &Path) -> Result<Input> {
+    let risk_map = input::grid(path)?;
+    check_risk_map(&risk_map).map_err(|err| err.in_file(path))?;
+    Ok(risk_map)
+}
+
+/// Lowest total risk of any path from the top left to the bottom right.
+pub fn part1(risk_map: &Input) -> Result<Answer> {
+    Ok(dijkstra(risk_map, &(0,0), &(risk_map.cols() - 1, risk_map.rows() -1)).into())
+}
+
+/// Lowest total risk across the full map, five times larger in each dimension.
+pub fn part2(risk_map: &Input) -> Result<Answer> {
+    let expanded_map = expand_map(risk_map);
+    Ok(dijkstra(&expanded_map, &(0,0), &(expanded_map.cols() - 1, expanded_map.rows() - 1)).into())
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Chiton";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
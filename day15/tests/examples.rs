@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day15::Day15;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day15>(env!("CARGO_MANIFEST_DIR"));
+}
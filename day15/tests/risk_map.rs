@@ -0,0 +1,23 @@
+use aoc_common::Grid;
+
+#[test]
+fn empty_maps_are_rejected() {
+    let risk_map: Grid<u32> = "".parse().unwrap();
+    assert_eq!(day15::check_risk_map(&risk_map).unwrap_err().to_string(),
+               "unexpected end of input, expected a map of risk levels");
+}
+
+#[test]
+fn zero_risk_is_rejected_where_it_is() {
+    let risk_map: Grid<u32> = "19\n10\n".parse().unwrap();
+    let err = day15::check_risk_map(&risk_map).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    assert_eq!(err.to_string(), "2:2: invalid token \"0\": risk levels run from 1 to 9");
+}
+
+#[test]
+fn single_cell_maps_cost_nothing() {
+    let risk_map: Grid<u32> = "5".parse().unwrap();
+    assert!(day15::check_risk_map(&risk_map).is_ok());
+    assert_eq!(day15::part1(&risk_map).unwrap().to_string(), "0");
+}
@@ -0,0 +1,247 @@
+//! Day 16: Packet Decoder
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day16;
+
+/// The payload of a packet: a literal value or the sub-packets of an operator.
+#[derive (Debug)]
+pub enum PacketType {
+    Literal(u64),
+    Operator(Vec<Packet>)
+}
+
+/// A decoded packet and, for operators, all of its sub-packets.
+#[derive(Debug)]
+pub struct Packet {
+    pub version: u64,
+    pub type_id: TypeId,
+    pub content: PacketType,
+    /// Length of the packet in bits, header included.
+    pub bits: usize
+}
+
+/// The operation a packet performs, from the type ID in its header.
+#[derive(Debug)]
+pub enum TypeId {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    Literal,
+    LessThan,
+    EqualTo
+}
+
+fn int_to_typeid(int: u64) -> Result<TypeId> {
+    match int {
+        0 => Ok(TypeId::Sum),
+        1 => Ok(TypeId::Product),
+        2 => Ok(TypeId::Minimum),
+        3 => Ok(TypeId::Maximum),
+        4 => Ok(TypeId::Literal),
+        5 => Ok(TypeId::GreaterThan),
+        6 => Ok(TypeId::LessThan),
+        7 => Ok(TypeId::EqualTo),
+        _ => Err(AocError::invalid_token(int, "packet type IDs run from 0 to 7"))
+    }
+}
+
+/// Reads the hexadecimal transmission as one bit per element.
+pub fn parse_file_to_binary(filename: &Path) -> Result<Vec<u8>> {
+    let bits = input::single_line(filename)?
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '0' => Ok(vec![0,0,0,0]),
+            '1' => Ok(vec![0,0,0,1]),
+            '2' => Ok(vec![0,0,1,0]),
+            '3' => Ok(vec![0,0,1,1]),
+            '4' => Ok(vec![0,1,0,0]),
+            '5' => Ok(vec![0,1,0,1]),
+            '6' => Ok(vec![0,1,1,0]),
+            '7' => Ok(vec![0,1,1,1]),
+            '8' => Ok(vec![1,0,0,0]),
+            '9' => Ok(vec![1,0,0,1]),
+            'A' => Ok(vec![1,0,1,0]),
+            'B' => Ok(vec![1,0,1,1]),
+            'C' => Ok(vec![1,1,0,0]),
+            'D' => Ok(vec![1,1,0,1]),
+            'E' => Ok(vec![1,1,1,0]),
+            'F' => Ok(vec![1,1,1,1]),
+            _ => Err(AocError::invalid_token(c, "expected a hexadecimal digit").at_column(i + 1).at_line(1).in_file(filename))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(bits.concat())
+}
+
+fn bits_to_dec(bits: &[u8]) -> u64 {
+    bits.iter().fold(0, |dec, bit| dec << 1 ^ *bit as u64)
+}
+
+// Errors while decoding carry the 1-based bit they refer to as their column,
+// relative to the slice being decoded.
+fn take_bits(bits: &[u8], len: usize) -> Result<&[u8]> {
+    bits.get(..len).ok_or_else(|| AocError::unexpected_eof(format!("{} more bits", len - bits.len()))
+                                     .at_column(bits.len() + 1))
+}
+
+fn parse_literal(bits: &[u8]) -> Result<(u64, usize)> {
+    let chunk_count = 1 + bits.iter().step_by(5).position(|b| b == &0)
+                                     .ok_or_else(|| AocError::unexpected_eof("the last group of a literal")
+                                                         .at_column(bits.len() + 1))?;
+    let literal :Vec<_> = take_bits(bits, chunk_count*5)?.chunks(5)
+                              .take(chunk_count)
+                              .flat_map(|chunk| chunk[1..5].to_vec())
+                              .collect();
+    Ok((bits_to_dec(&literal), chunk_count*5))
+}
+
+fn parse_operator(bits: &[u8]) -> Result<(Vec<Packet>, usize)> {
+    let length_type_id = take_bits(bits, 1)?[0];
+    match length_type_id {
+        0 => {
+            let length_in_bits = bits_to_dec(&take_bits(bits, 16)?[1..16]) as usize;
+            let sub_packet_bits = take_bits(bits, 16 + length_in_bits)?;
+            let mut packets = Vec::new();
+            let mut start = 16;
+            while start < 16 + length_in_bits {
+                let packet = parse_packet(&sub_packet_bits[start..]).map_err(|err| err.at_token(start + 1))?;
+                start += packet.bits;
+                packets.push(packet);
+            };
+            Ok((packets, length_in_bits + 16)) // 15 bit number + 1 bit id
+        }
+        1 => {
+            let num_packets = bits_to_dec(&take_bits(bits, 12)?[1..12]);
+            let mut start = 12;
+            let mut packets = Vec::new();
+            for _ in 0..num_packets {
+                let packet = parse_packet(&bits[start..]).map_err(|err| err.at_token(start + 1))?;
+                start += packet.bits;
+                packets.push(packet);
+            }
+            Ok((packets, start))
+        }
+        _ => {
+            Err(AocError::invalid_token(length_type_id, "length type IDs are a single bit").at_column(1))
+        }
+    }
+}
+
+/// Decodes the packet at the start of `input`. Errors report the bit at
+/// fault as their column.
+pub fn parse_packet(input: &[u8]) -> Result<Packet> {
+    let header = take_bits(input, 6)?;
+    let version = bits_to_dec(&header[..3]);
+    let type_id = int_to_typeid(bits_to_dec(&header[3..6])).map_err(|err| err.at_column(4))?;
+
+    let (content, bits) = match type_id {
+        TypeId::Literal => {
+            let (value, bits) = parse_literal(&input[6..]).map_err(|err| err.at_token(7))?;
+            (PacketType::Literal(value), bits)
+        },
+        _ => {
+            let (value, bits) = parse_operator(&input[6..]).map_err(|err| err.at_token(7))?;
+            (PacketType::Operator(value), bits)
+        }
+    };
+
+    // make sure calculate_value has the operands each operator needs
+    let operands = match &content {
+        PacketType::Operator(sub_packets) => sub_packets.len(),
+        PacketType::Literal(_) => 0,
+    };
+    match type_id {
+        TypeId::Minimum | TypeId::Maximum if operands == 0 =>
+            return Err(AocError::format(format!("{:?} packet has no sub-packets", type_id)).at_column(1)),
+        TypeId::GreaterThan | TypeId::LessThan | TypeId::EqualTo if operands != 2 =>
+            return Err(AocError::format(format!("{:?} packet needs 2 sub-packets, found {}", type_id, operands))
+                           .at_column(1)),
+        _ => (),
+    }
+
+    Ok(Packet {
+        version,
+        type_id,
+        content,
+        bits: bits + 6 // 6 bit header
+    })
+}
+
+/// Sum of the version numbers of the packet and all of its sub-packets.
+pub fn get_version_number_sum(packet: &Packet) -> u64 {
+    match &packet.content {
+       PacketType::Literal(_) => packet.version,
+       PacketType::Operator(sub_packets) => 
+           sub_packets.iter()
+                       .fold(packet.version, |acc, sub_packet| 
+                             acc + get_version_number_sum(sub_packet))
+    }
+}
+
+/// Evaluates the packet expression. Packets decoded by `parse_packet` always
+/// have the operands their operator needs.
+pub fn calculate_value(packet: &Packet) -> u64 {
+    match &packet.content {
+        PacketType::Literal(value) => *value,
+        PacketType::Operator(sub_packets) => {
+            let mut sub_packet_values = sub_packets.iter().map(calculate_value);
+            match packet.type_id {
+                TypeId::Sum => sub_packet_values.sum(),
+                TypeId::Product => sub_packet_values.product(),
+                TypeId::Minimum => sub_packet_values.min().unwrap(),
+                TypeId::Maximum => sub_packet_values.max().unwrap(),
+                TypeId::GreaterThan => 
+                    if sub_packet_values.next().unwrap() > 
+                        sub_packet_values.next().unwrap() { 1 } else { 0 },
+                TypeId::Literal => unreachable!(),
+                TypeId::LessThan => 
+                    if sub_packet_values.next().unwrap() <
+                        sub_packet_values.next().unwrap() { 1 } else { 0 },
+                TypeId::EqualTo => 
+                    if sub_packet_values.next().unwrap() ==
+                        sub_packet_values.next().unwrap() { 1 } else { 0 },
+            }
+        }
+    }
+}
+
+pub type Input = Packet;
+
+/// Reads the hexadecimal transmission and decodes its outermost packet.
+pub fn parse(path: &Path) -> Result<Input> {
+    // report the hexadecimal digit holding the bit at fault
+    parse_packet(&parse_file_to_binary(path)?)
+        .map_err(|err| AocError { column: err.column.map(|bit| (bit - 1) / 4 + 1), ..err }.at_line(1).in_file(path))
+}
+
+/// Sum of the version numbers of every packet.
+pub fn part1(packet: &Input) -> Result<Answer> {
+    Ok(get_version_number_sum(packet).into())
+}
+
+/// Value of the outermost packet once every operator is evaluated.
+pub fn part2(packet: &Input) -> Result<Answer> {
+    Ok(calculate_value(packet).into())
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day16::Day16;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day16>(env!("CARGO_MANIFEST_DIR"));
+}
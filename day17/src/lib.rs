@@ -0,0 +1,141 @@
+//! Day 17: Trick Shot
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day17;
+
+/// The target area. `min_y` is its top edge and `max_y` its bottom edge.
+pub struct Target {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+// integer suvat
+// s = sum[i=0->t] u + a*t_i
+// v = u + at
+
+/// Whether a launch with vertical velocity `u` is between the `min` (top) and
+/// `max` (bottom) heights on some step.
+pub fn falls_in_bounds(u: i32, min: i32, max: i32) -> bool {
+    assert!(max < 0);
+    let a = -1;
+    let mut t = 0;
+    let mut s = 0;
+    while s >= max {
+        s += u + a*t;
+        if (max..=min).contains(&s) {
+            return true;
+        }
+        t += 1;
+    }
+    false
+}
+
+/// Whether a launch with velocity `(u_x, u_y)` is within the target on some step.
+pub fn falls_in_bounds_2d(u_x: i32, u_y: i32, min_x: i32, max_x: i32, min_y: i32, max_y: i32) -> bool {
+    let mut s_x = 0;
+    let mut s_y = 0;
+    let mut v_x = u_x;
+    let mut v_y = u_y;
+    while s_y >= max_y && s_x <= max_x {
+        s_x += v_x;
+        s_y += v_y;
+        if (min_x..=max_x).contains(&s_x) && (max_y..=min_y).contains(&s_y) {
+            return true;
+        }
+        v_x = std::cmp::max(v_x - 1, 0);
+        v_y -= 1;
+    }
+    false
+}
+
+fn get_distance(u : i32, t: i32) -> i32 {
+    (0..t).fold(0, |s, t_i| s + u - t_i)
+}
+
+/// Largest vertical velocity that still lands between `min` (top) and `max` (bottom).
+pub fn find_max_vertical_velocity(min: i32, max: i32) -> i32 {
+    let mut max_u = 0;
+    for u in 0..10000 {
+        if falls_in_bounds(u, min, max) {
+            max_u = u;
+        }
+    }
+    max_u
+}
+
+/// Every initial velocity that lands in the target area.
+pub fn find_all_trajectories(min_x: i32, max_x: i32, min_y: i32, max_y: i32) -> Vec<(i32,i32)> {
+    let mut trajectories = Vec::new();
+    for u_x in -2000..2000 {
+        for u_y in -2000..2000 {
+            if falls_in_bounds_2d(u_x, u_y, min_x, max_x, min_y, max_y) {
+                trajectories.push((u_x, u_y));
+            }
+        }
+    }
+    trajectories
+}
+
+fn parse_range(range: &str) -> Option<(i32, i32)> {
+    let (start, end) = range.get(2..)?.split_once("..")?;
+    Some((start.parse().ok()?, end.parse().ok()?))
+}
+
+fn get_target(filename: &Path) -> Result<Target> {
+    let line = input::single_line(filename)?;
+    let ranges = line.strip_prefix("target area: ")
+                     .and_then(|ranges| ranges.split_once(", "))
+                     .and_then(|(x, y)| Some((parse_range(x)?, parse_range(y)?)));
+
+    // min_y is the top of the target and max_y the bottom, as in falls_in_bounds
+    match ranges {
+        Some(((min_x, max_x), (bottom, top))) if bottom <= top && top < 0 =>
+            Ok(Target { min_x, max_x, min_y: top, max_y: bottom }),
+        Some(_) => Err(AocError::no_solution("the target area must lie entirely below the launcher")
+                           .at_line(1)
+                           .in_file(filename)),
+        None => Err(AocError::format(format!("expected \"target area: x=A..B, y=C..D\", found {:?}", line))
+                        .at_line(1)
+                        .in_file(filename)),
+    }
+}
+
+pub type Input = Target;
+
+/// Reads the target area.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_target(path)
+}
+
+/// Highest y position reachable by a probe that ends up in the target area.
+pub fn part1(target: &Input) -> Result<Answer> {
+    let max_vel = find_max_vertical_velocity(target.min_y, target.max_y);
+    Ok(get_distance(max_vel, max_vel).into())
+}
+
+/// Number of initial velocities that land the probe in the target area.
+pub fn part2(target: &Input) -> Result<Answer> {
+    Ok(find_all_trajectories(target.min_x, target.max_x, target.min_y, target.max_y).len().into())
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Trick Shot";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day17::Day17;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day17>(env!("CARGO_MANIFEST_DIR"));
+}
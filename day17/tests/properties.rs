@@ -0,0 +1,37 @@
+use proptest::prelude::*;
+
+/// The top and bottom of a target area below the launcher.
+fn heights() -> impl Strategy<Value = (i32, i32)> {
+    (-100..0_i32).prop_flat_map(|top| (Just(top), -150..=top))
+}
+
+proptest! {
+    #[test]
+    fn vertical_search_matches_2d_search_without_drift((top, bottom) in heights(), u_y in -200..200_i32) {
+        // with no horizontal velocity the probe never leaves x = 0
+        prop_assert_eq!(day17::falls_in_bounds(u_y, top, bottom),
+                        day17::falls_in_bounds_2d(0, u_y, 0, 0, top, bottom));
+    }
+
+    #[test]
+    fn hits_in_2d_also_hit_vertically((top, bottom) in heights(),
+                                     (min_x, max_x) in (0..50_i32).prop_flat_map(|min_x| (Just(min_x), min_x..100)),
+                                     u_x in -10..110_i32,
+                                     u_y in -200..200_i32) {
+        if day17::falls_in_bounds_2d(u_x, u_y, min_x, max_x, top, bottom) {
+            prop_assert!(day17::falls_in_bounds(u_y, top, bottom));
+        }
+    }
+}
+
+proptest! {
+    // each case searches ten thousand launches
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn highest_launch_just_reaches_the_bottom_edge((top, bottom) in heights()) {
+        // the probe falls back past y = 0 at -(u + 1), so the fastest that
+        // still lands is the one whose next step ends on the bottom edge
+        prop_assert_eq!(day17::find_max_vertical_velocity(top, bottom), -bottom - 1);
+    }
+}
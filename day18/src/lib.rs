@@ -0,0 +1,272 @@
+//! Day 18: Snailfish
+use std::path::Path;
+use std::fmt;
+use itertools::Itertools;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day18;
+
+/// One side of a snailfish pair: a regular number or a nested pair.
+#[derive(Debug, Clone)]
+pub enum Type {
+    Raw(u32),
+    Nested(Box<SFNum>),
+}
+
+impl Type {
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            Type::Raw(value) => *value as u64,
+            Type::Nested(ref nested) => nested.magnitude()
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) ->  fmt::Result {
+        match self {
+            Type::Raw(value) => write!(f, "{}", value),
+            Type::Nested(nested) => write!(f, "{}", nested)
+        }
+    }
+}
+
+/// A snailfish number, always a pair.
+#[derive(Debug, Clone)]
+pub struct SFNum {
+    pub left: Type,
+    pub right: Type,
+}
+
+type Explosion = (Option<u32>, Option<u32>);
+
+impl SFNum {
+    /// Explodes and splits until neither applies. Addition reduces its result.
+    pub fn reduce(&mut self) {
+        loop {
+            if self.try_explode(0).is_some() {
+                continue;
+            }
+            if self.try_split() {
+                continue;
+            }
+            break;
+        }
+    }
+
+    fn try_explode(&mut self, level: u32) -> Option<Explosion> {
+        // explode first
+        if level >= 4 {
+            //println!("{} explodes", self);
+            if let Type::Raw(left) = self.left {
+                if let Type::Raw(right) = self.right {
+                    return Some((Some(left), Some(right)));
+                }
+            }
+        }
+
+        // explode left and propagate explosion
+        if let Type::Nested(ref mut left) = self.left {
+            if let Some((left_exp, right_exp)) = left.try_explode(level + 1) {
+                //println!("propagate explosion {:?} left {}", (left_exp, right_exp), self);
+                if let Some(right_exp_val) = right_exp { 
+                    if left_exp.is_some() {
+                        //println!("set {} to 0", self.left);
+                        self.left = Type::Raw(0);
+                    }
+                    match self.right {
+                        Type::Raw(ref right_val) => {
+                            //println!("set {} to {}", self.right, right_val + right_exp_val);
+                            self.right = Type::Raw(right_val + right_exp_val);
+                        },
+                        Type::Nested(ref mut right_nested) => right_nested.add_left(right_exp_val),
+                    }
+                    //println!("value now: {}", self);
+                }
+                return Some((left_exp, None)); 
+            }
+        }
+
+        // explode right and propagate explosion
+        if let Type::Nested(ref mut right) = self.right {
+            if let Some((left_exp, right_exp)) = right.try_explode(level + 1) {
+                //println!("propagate explosion {:?} right {}", (left_exp, right_exp), self);
+                if let Some(left_exp_val) = left_exp { 
+                    if right_exp.is_some() {
+                        //println!("set {} to 0", self.right);
+                        self.right = Type::Raw(0);
+                    }
+                    match self.left {
+                        Type::Raw(ref left_val) => {
+                            //println!("set {} to {}", self.left, left_val + left_exp_val);
+                            self.left = Type::Raw(left_val + left_exp_val)
+                        },
+                        Type::Nested(ref mut left_nested) => left_nested.add_right(left_exp_val),
+                    }
+                    //println!("value now: {}", self);
+                }
+                return Some((None, right_exp)); 
+            }
+        }
+        None
+    }
+
+    fn try_split(&mut self) -> bool {
+        for node in [&mut self.left, &mut self.right] {
+            match node {
+                Type::Raw(val) => {
+                    if *val > 9 {
+                        let split = *val as f32 / 2.0;
+                        *node = Type::Nested(
+                            Box::new(SFNum {
+                                left: Type::Raw(split.floor() as u32),
+                                right: Type::Raw(split.ceil() as u32),
+                                }));
+                       return true;
+                    }
+                },
+                Type::Nested(nested) => { 
+                    if nested.try_split() { 
+                        return true; 
+                    } 
+                } 
+            }
+        }
+        false
+    }
+
+    fn add_right(&mut self, value: u32) {
+        //println!("propagate {} down to {}", value, self.right);
+        match &mut self.right {
+            Type::Raw(raw) => self.right = Type::Raw(*raw + value),
+            Type::Nested(nested) => nested.add_right(value)
+        }
+    }
+
+    fn add_left(&mut self, value: u32) {
+        //println!("propagate {} down to {}", value, self.left);
+        match &mut self.left {
+            Type::Raw(raw) => self.left = Type::Raw(*raw + value),
+            Type::Nested(nested) => nested.add_left(value)
+        }
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        3 * self.left.magnitude() + 2 * self.right.magnitude()
+    }
+
+}
+
+impl fmt::Display for SFNum {
+    fn fmt(&self, f: &mut fmt::Formatter) ->  fmt::Result {
+        write!(f, "[{},{}]", self.left, self.right)
+    }
+}
+
+impl std::ops::Add<SFNum> for SFNum {
+    type Output = SFNum;
+    fn add(self, rhs: SFNum) -> SFNum {
+        let mut result = SFNum {
+            left: Type::Nested(Box::new(self)),
+            right: Type::Nested(Box::new(rhs)),
+        };
+        result.reduce();
+        result
+    }
+}
+
+fn get_file_contents(filename: &Path) -> Result<Vec<SFNum>> {
+    input::parse_lines_with(filename, |line| {
+        let chars = line.chars().collect::<Vec<_>>();
+        let (number, end) = parse_to_sfnum(&chars)?;
+        match chars.get(end + 1) {
+            Some(c) => Err(AocError::invalid_token(c, "expected the end of the line").at_column(end + 2)),
+            None => Ok(number),
+        }
+    })
+}
+
+/// Parses a snailfish number, returning it and the index of its closing `]`.
+pub fn parse_to_sfnum(line: &[char]) -> Result<(SFNum, usize)> {
+    let expect = |i: usize, expected: char| match line.get(i) {
+        Some(&c) if c == expected => Ok(()),
+        Some(&c) => Err(AocError::invalid_token(c, format!("expected {:?}", expected)).at_column(i + 1)),
+        None => Err(AocError::unexpected_eof(format!("{:?}", expected)).at_column(i + 1)),
+    };
+    // parses the element starting at i, returning it and the index following it
+    let element = |i: usize| match line.get(i) {
+        Some('[') => {
+            let (val, end) = parse_to_sfnum(&line[i..]).map_err(|err| err.at_token(i + 1))?;
+            Ok((Type::Nested(Box::new(val)), i + end + 1))
+        },
+        Some(n) => n.to_digit(10)
+                    .map(|value| (Type::Raw(value), i + 1))
+                    .ok_or_else(|| AocError::invalid_token(n, "expected a digit or a pair").at_column(i + 1)),
+        None => Err(AocError::unexpected_eof("a digit or a pair").at_column(i + 1)),
+    };
+
+    expect(0, '[')?;
+    let (left, i) = element(1)?;
+    expect(i, ',')?;
+    let (right, i) = element(i + 1)?;
+    expect(i, ']')?;
+    Ok((SFNum { left, right }, i))
+}
+
+/// Sum of all the numbers, added in order.
+pub fn final_sum(numbers: &[SFNum]) -> SFNum {
+    let start = numbers[0].clone();
+    numbers.iter().skip(1).fold(start, |acc, x| acc + x.clone())
+}
+
+/// Largest magnitude of the sum of any two different numbers.
+pub fn max_magnitude(numbers: &[SFNum]) -> u64 {
+    numbers.iter()
+           .tuple_combinations()
+           .map(|(a,b)| std::cmp::max((a.clone()+b.clone()).magnitude(), 
+                                      (b.clone()+a.clone()).magnitude()))
+           .max()
+           .unwrap()
+}
+
+pub type Input = Vec<SFNum>;
+
+/// Reads the snailfish numbers, one per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Magnitude of the sum of every number in order.
+pub fn part1(numbers: &Input) -> Result<Answer> {
+    if numbers.is_empty() {
+        return Err(AocError::no_solution("there are no numbers to add"));
+    }
+    Ok(final_sum(numbers).magnitude().into())
+}
+
+/// Largest magnitude of the sum of any two different numbers.
+pub fn part2(numbers: &Input) -> Result<Answer> {
+    if numbers.len() < 2 {
+        return Err(AocError::no_solution("there are fewer than two numbers to add"));
+    }
+    Ok(max_magnitude(numbers).into())
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Snailfish";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day18::Day18;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day18>(env!("CARGO_MANIFEST_DIR"));
+}
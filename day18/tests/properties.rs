@@ -0,0 +1,60 @@
+use day18::{SFNum, Type};
+use proptest::prelude::*;
+
+/// A reduced snailfish number: regular numbers up to 9, at most four pairs deep.
+fn sfnum() -> impl Strategy<Value = SFNum> {
+    let element = (0..=9_u32).prop_map(Type::Raw).prop_recursive(3, 16, 2, |inner| {
+        (inner.clone(), inner).prop_map(|(left, right)| Type::Nested(Box::new(SFNum { left, right })))
+    });
+    (element.clone(), element).prop_map(|(left, right)| SFNum { left, right })
+}
+
+/// How many pairs deep the number goes, counting itself.
+fn depth(number: &SFNum) -> usize {
+    let element_depth = |element: &Type| match element {
+        Type::Raw(_) => 0,
+        Type::Nested(nested) => depth(nested),
+    };
+    1 + std::cmp::max(element_depth(&number.left), element_depth(&number.right))
+}
+
+fn largest_value(number: &SFNum) -> u32 {
+    let element_value = |element: &Type| match element {
+        Type::Raw(value) => *value,
+        Type::Nested(nested) => largest_value(nested),
+    };
+    std::cmp::max(element_value(&number.left), element_value(&number.right))
+}
+
+fn parse(text: &str) -> SFNum {
+    day18::parse_to_sfnum(&text.chars().collect::<Vec<_>>()).unwrap().0
+}
+
+proptest! {
+    #[test]
+    fn sums_are_reduced(a in sfnum(), b in sfnum()) {
+        let sum = a + b;
+        prop_assert!(depth(&sum) <= 4, "{} is nested too deeply", sum);
+        prop_assert!(largest_value(&sum) <= 9, "{} needs splitting", sum);
+    }
+
+    #[test]
+    fn reducing_a_reduced_number_changes_nothing(a in sfnum(), b in sfnum()) {
+        let sum = a + b;
+        let mut reduced = sum.clone();
+        reduced.reduce();
+        prop_assert_eq!(reduced.to_string(), sum.to_string());
+    }
+
+    #[test]
+    fn numbers_print_as_they_parse(number in sfnum()) {
+        let text = number.to_string();
+        prop_assert_eq!(parse(&text).to_string(), text);
+    }
+
+    #[test]
+    fn magnitude_weighs_left_thrice_and_right_twice(number in sfnum()) {
+        let doubled = SFNum { left: Type::Nested(Box::new(number.clone())), right: Type::Raw(0) };
+        prop_assert_eq!(doubled.magnitude(), 3 * number.magnitude());
+    }
+}
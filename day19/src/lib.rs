@@ -0,0 +1,206 @@
+//! Day 19: Beacon Scanner
+use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day19;
+
+pub type Point = [i32; 3];
+pub type Rotation = [[i32; 3]; 3];
+
+/// Beacons two scanners must share before they count as overlapping.
+pub const MIN_OVERLAP: usize = 12;
+
+fn parse_header(line: &str, expected: usize) -> Result<()> {
+    let number = line.trim()
+                     .strip_prefix("--- scanner ")
+                     .and_then(|rest| rest.strip_suffix(" ---"))
+                     .ok_or_else(|| AocError::format("expected a header such as --- scanner 0 ---"))?;
+    match input::parse_token::<usize>(number).map_err(|err| err.at_column(13))? {
+        n if n == expected => Ok(()),
+        n => Err(AocError::invalid_token(n, format!("expected scanner {}", expected)).at_column(13)),
+    }
+}
+
+fn parse_beacon(line: &str) -> Result<Point> {
+    let coords = input::separated(line, ',')
+        .map(|(column, token)| input::parse_token(token).map_err(|err| err.at_column(column)))
+        .collect::<Result<Vec<i32>>>()?;
+    match coords[..] {
+        [x, y, z] => Ok([x, y, z]),
+        _ => Err(AocError::invalid_token(line, "expected a beacon such as 404,-588,-901")),
+    }
+}
+
+fn get_file_contents(filename: &Path) -> Result<Vec<Vec<Point>>> {
+    let mut scanners: Vec<Vec<Point>> = Vec::new();
+    for section in input::sections(filename)? {
+        let mut lines = section.iter();
+        if let Some((number, header)) = lines.next() {
+            parse_header(header, scanners.len()).map_err(|err| err.at_line(*number).in_file(filename))?;
+        }
+        let beacons = lines.map(|(number, line)| parse_beacon(line).map_err(|err| err.at_line(*number).in_file(filename)))
+                           .collect::<Result<Vec<Point>>>()?;
+        scanners.push(beacons);
+    }
+    Ok(scanners)
+}
+
+fn determinant(m: &Rotation) -> i32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+fn is_orthonormal(m: &Rotation) -> bool {
+    (0..3).all(|i| (0..3).all(|j| {
+        let dot: i32 = (0..3).map(|k| m[i][k] * m[j][k]).sum();
+        dot == if i == j { 1 } else { 0 }
+    }))
+}
+
+/// The 24 proper rotations: every matrix of -1, 0 and 1 that is orthonormal
+/// with a determinant of 1, so it turns a scanner without mirroring it.
+pub fn all_rotations() -> Vec<Rotation> {
+    (0..3_usize.pow(9))
+        .map(|n| {
+            let mut m = [[0; 3]; 3];
+            for (k, cell) in m.iter_mut().flatten().enumerate() {
+                *cell = (n / 3_usize.pow(k as u32) % 3) as i32 - 1;
+            }
+            m
+        })
+        .filter(|m| determinant(m) == 1 && is_orthonormal(m))
+        .collect()
+}
+
+/// `point` turned by `rotation`.
+pub fn rotate(rotation: &Rotation, point: &Point) -> Point {
+    let mut rotated = [0; 3];
+    for (row, value) in rotation.iter().zip(rotated.iter_mut()) {
+        *value = row.iter().zip(point).map(|(a, b)| a * b).sum();
+    }
+    rotated
+}
+
+fn offset(a: &Point, b: &Point) -> Point {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// The rotation and position that put `beacons` into the frame of
+/// `reference`, if at least `MIN_OVERLAP` of them then coincide.
+pub fn find_transform(reference: &[Point], beacons: &[Point], rotations: &[Rotation]) -> Option<(Rotation, Point)> {
+    rotations.iter().find_map(|rotation| {
+        let rotated: Vec<Point> = beacons.iter().map(|beacon| rotate(rotation, beacon)).collect();
+        let mut votes: HashMap<Point, usize> = HashMap::new();
+        for a in reference {
+            for b in &rotated {
+                let count = votes.entry(offset(a, b)).or_insert(0);
+                *count += 1;
+                if *count >= MIN_OVERLAP {
+                    return Some((*rotation, offset(a, b)));
+                }
+            }
+        }
+        None
+    })
+}
+
+/// A scanner placed in the frame of scanner 0.
+#[derive(Debug, Clone)]
+pub struct Aligned {
+    pub position: Point,
+    /// Its beacons, relative to scanner 0.
+    pub beacons: Vec<Point>,
+}
+
+/// Places every scanner relative to scanner 0 by chaining overlapping pairs.
+pub fn align(scanners: &[Vec<Point>]) -> Result<Vec<Aligned>> {
+    let rotations = all_rotations();
+    let mut aligned: Vec<Option<Aligned>> = vec![None; scanners.len()];
+    let first = scanners.first().ok_or_else(|| AocError::no_solution("there are no scanners"))?;
+    aligned[0] = Some(Aligned { position: [0, 0, 0], beacons: first.clone() });
+
+    // each newly placed scanner is only compared once against those left
+    let mut queue = vec![0];
+    while let Some(reference) = queue.pop() {
+        for i in 0..scanners.len() {
+            if aligned[i].is_some() {
+                continue;
+            }
+            let reference_beacons = &aligned[reference].as_ref().unwrap().beacons;
+            if let Some((rotation, position)) = find_transform(reference_beacons, &scanners[i], &rotations) {
+                let beacons = scanners[i].iter()
+                                         .map(|beacon| rotate(&rotation, beacon))
+                                         .map(|beacon| [beacon[0] + position[0],
+                                                        beacon[1] + position[1],
+                                                        beacon[2] + position[2]])
+                                         .collect();
+                aligned[i] = Some(Aligned { position, beacons });
+                queue.push(i);
+            }
+        }
+    }
+
+    aligned.into_iter()
+           .enumerate()
+           .map(|(i, scanner)| scanner.ok_or_else(||
+               AocError::no_solution(format!("scanner {} overlaps none of the others", i))))
+           .collect()
+}
+
+/// Number of distinct beacons seen by the aligned scanners.
+pub fn count_beacons(aligned: &[Aligned]) -> usize {
+    aligned.iter()
+           .flat_map(|scanner| scanner.beacons.iter())
+           .collect::<HashSet<_>>()
+           .len()
+}
+
+pub fn manhattan_distance(a: &Point, b: &Point) -> i32 {
+    offset(a, b).iter().map(|d| d.abs()).sum()
+}
+
+/// Largest Manhattan distance between any two aligned scanners.
+pub fn max_distance(aligned: &[Aligned]) -> i32 {
+    aligned.iter()
+           .flat_map(|a| aligned.iter().map(move |b| manhattan_distance(&a.position, &b.position)))
+           .max()
+           .unwrap_or(0)
+}
+
+pub type Input = Vec<Vec<Point>>;
+
+/// Reads the beacons each scanner reports, in scanner order.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Number of beacons once every scanner is aligned.
+pub fn part1(scanners: &Input) -> Result<Answer> {
+    Ok(count_beacons(&align(scanners)?).into())
+}
+
+/// Largest Manhattan distance between any two scanners.
+pub fn part2(scanners: &Input) -> Result<Answer> {
+    Ok(max_distance(&align(scanners)?).into())
+}
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
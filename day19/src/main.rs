@@ -0,0 +1,24 @@
+use std::path::Path;
+use std::process;
+
+use aoc_common::input;
+
+fn run(path: &Path) -> aoc_common::Result<()> {
+    let scanners = day19::parse(path)?;
+
+    println!("Number of beacons: {}", day19::part1(&scanners)?);
+    println!("Max scanner manhattan distance: {}", day19::part2(&scanners)?);
+    Ok(())
+}
+
+fn main() {
+    let path = input::path_from_args().unwrap_or_else(|usage| {
+        eprintln!("{}", usage);
+        process::exit(2);
+    });
+
+    if let Err(err) = run(&path) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
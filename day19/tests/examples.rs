@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day19::Day19;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day19>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use day19::{Point, Rotation, MIN_OVERLAP};
+
+const IDENTITY: Rotation = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+
+#[test]
+fn there_are_24_distinct_rotations() {
+    let rotations = day19::all_rotations();
+    assert_eq!(rotations.len(), 24);
+    assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+    assert!(rotations.contains(&IDENTITY));
+}
+
+#[test]
+fn rotations_turn_a_point_every_way() {
+    let images: HashSet<Point> = day19::all_rotations().iter().map(|rotation| day19::rotate(rotation, &[1, 2, 3])).collect();
+    assert_eq!(images.len(), 24);
+    // a mirror image is not a rotation
+    assert!(!images.contains(&[-1, 2, 3]));
+}
+
+#[test]
+fn transforms_are_recovered_from_enough_shared_beacons() {
+    let reference: Vec<Point> = (0..MIN_OVERLAP as i32).map(|i| [i, i * i, 3 * i - 7]).collect();
+    // the same beacons seen from a scanner at (5, -3, 10), facing along -y
+    let turn: Rotation = [[0, -1, 0], [1, 0, 0], [0, 0, 1]];
+    let position = [5, -3, 10];
+    let unturn: Rotation = [[0, 1, 0], [-1, 0, 0], [0, 0, 1]];
+    let seen: Vec<Point> = reference.iter()
+                                    .map(|b| [b[0] - position[0], b[1] - position[1], b[2] - position[2]])
+                                    .map(|relative| day19::rotate(&unturn, &relative))
+                                    .collect();
+
+    let rotations = day19::all_rotations();
+    assert_eq!(day19::find_transform(&reference, &seen, &rotations), Some((turn, position)));
+    assert_eq!(day19::find_transform(&reference, &seen[1..], &rotations), None);
+}
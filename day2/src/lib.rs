@@ -0,0 +1,390 @@
+//! Day 2: Dive!
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day2;
+
+/// One command of the planned course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+    /// Moves backwards, retracing a forward move of the same distance.
+    Back(i32),
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Command::Forward(distance) => write!(f, "forward {}", distance),
+            Command::Up(distance) => write!(f, "up {}", distance),
+            Command::Down(distance) => write!(f, "down {}", distance),
+            Command::Back(distance) => write!(f, "back {}", distance),
+        }
+    }
+}
+
+/// A command together with the 1-based line and column its direction is
+/// written at. Commands expanded from a repeat or a macro keep the position
+/// of the command in the block they were written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub command: Command,
+    pub line: usize,
+    pub column: usize,
+}
+
+fn to_command(direction: &str, distance: i32) -> Option<Command> {
+    match direction {
+        "forward" => Some(Command::Forward(distance)),
+        "up" => Some(Command::Up(distance)),
+        "down" => Some(Command::Down(distance)),
+        "back" => Some(Command::Back(distance)),
+        _ => None,
+    }
+}
+
+/// A word or brace of a course file, with the 1-based line and column it starts at.
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+fn tokenize(lines: &[String]) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let code = line.split('#').next().unwrap();
+        let mut start = None;
+        for (j, c) in code.char_indices().chain(std::iter::once((code.len(), ' '))) {
+            if c.is_whitespace() || c == '{' || c == '}' {
+                if let Some(start) = start.take() {
+                    tokens.push(Token { text: &code[start..j], line: i + 1, column: start + 1 });
+                }
+                if c == '{' || c == '}' {
+                    tokens.push(Token { text: &code[j..j + 1], line: i + 1, column: j + 1 });
+                }
+            }
+            else if start.is_none() {
+                start = Some(j);
+            }
+        }
+    }
+    tokens
+}
+
+/// Most commands a course may expand to, so a few nested repeats cannot
+/// exhaust memory.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+struct CourseParser<'a> {
+    tokens: std::vec::IntoIter<Token<'a>>,
+    macros: HashMap<&'a str, Vec<Instruction>>,
+    /// Where the input ends, for errors about what is missing there.
+    end: (usize, usize),
+}
+
+fn located(token: &Token, err: AocError) -> AocError {
+    err.at_column(token.column).at_line(token.line)
+}
+
+/// Appends `count` copies of `body` to `commands`, unless that would take
+/// the course past `MAX_COMMANDS`.
+fn expand(commands: &mut Vec<Instruction>, body: &[Instruction], count: usize, token: &Token) -> Result<()> {
+    if body.len().saturating_mul(count) > MAX_COMMANDS - commands.len() {
+        return Err(located(token, AocError::format(format!("the course expands to more than {} commands",
+                                                          MAX_COMMANDS))));
+    }
+    for _ in 0..count {
+        commands.extend_from_slice(body);
+    }
+    Ok(())
+}
+
+impl<'a> CourseParser<'a> {
+    fn missing(&self, expected: String) -> AocError {
+        AocError::unexpected_eof(expected).at_column(self.end.1).at_line(self.end.0)
+    }
+
+    fn value<T>(&mut self, expected: &str) -> Result<T>
+        where T: std::str::FromStr, T::Err: fmt::Display {
+        let token = self.tokens.next().ok_or_else(|| self.missing(expected.to_string()))?;
+        input::parse_token(token.text).map_err(|err| located(&token, err))
+    }
+
+    fn open_block(&mut self) -> Result<Token<'a>> {
+        match self.tokens.next() {
+            Some(token) if token.text == "{" => Ok(token),
+            Some(token) => Err(located(&token, AocError::invalid_token(token.text, "expected {"))),
+            None => Err(self.missing("{".to_string())),
+        }
+    }
+
+    /// Commands up to the `}` closing `open`, or to the end of the input if
+    /// this is the top level.
+    fn block(&mut self, open: Option<&Token>) -> Result<Vec<Instruction>> {
+        let mut commands = Vec::new();
+        loop {
+            let token = match self.tokens.next() {
+                Some(token) => token,
+                None => return match open {
+                    Some(open) => Err(self.missing(format!("}} closing the block opened at {}:{}",
+                                                           open.line, open.column))),
+                    None => Ok(commands),
+                },
+            };
+            match token.text {
+                "}" if open.is_some() => return Ok(commands),
+                "}" => return Err(located(&token, AocError::invalid_token("}", "there is no block to close"))),
+                "repeat" => {
+                    let count: usize = self.value("a repeat count")?;
+                    let open = self.open_block()?;
+                    let body = self.block(Some(&open))?;
+                    expand(&mut commands, &body, count, &token)?;
+                },
+                "macro" if open.is_some() =>
+                    return Err(located(&token, AocError::invalid_token("macro", "macros are defined at the top level"))),
+                "macro" => {
+                    let name = self.tokens.next().ok_or_else(|| self.missing("a macro name".to_string()))?;
+                    if matches!(name.text, "{" | "}" | "repeat" | "macro") || to_command(name.text, 0).is_some() {
+                        return Err(located(&name, AocError::invalid_token(name.text, "expected a macro name")));
+                    }
+                    let open = self.open_block()?;
+                    let body = self.block(Some(&open))?;
+                    self.macros.insert(name.text, body);
+                },
+                direction => match to_command(direction, 0) {
+                    Some(_) => {
+                        let distance = self.value("a distance")?;
+                        commands.push(Instruction {
+                            command: to_command(direction, distance).unwrap(),
+                            line: token.line,
+                            column: token.column,
+                        });
+                    },
+                    None => {
+                        let body = self.macros.get(direction).ok_or_else(|| located(&token, AocError::invalid_token(
+                            direction, "expected forward, up, down, back, repeat, macro or a defined macro")))?;
+                        expand(&mut commands, body, 1, &token)?;
+                    },
+                },
+            }
+        }
+    }
+}
+
+/// Parses a course file into the commands it expands to. Besides one
+/// `direction distance` command per line, a course may use:
+///
+/// ```text
+/// # comments, to the end of the line
+/// macro dive {          # defines a macro at the top level
+///     down 5
+///     forward 2
+/// }
+/// repeat 3 { dive up 1 } # repeats a block, which may span lines
+/// back 4                 # moves backwards
+/// ```
+///
+/// A macro is used by its name once it is defined.
+pub fn parse_course(lines: &[String]) -> Result<Vec<Instruction>> {
+    let end = (lines.len().max(1), lines.last().map_or(1, |line| line.len() + 1));
+    let mut parser = CourseParser { tokens: tokenize(lines).into_iter(), macros: HashMap::new(), end };
+    parser.block(None)
+}
+
+/// How a submarine moves in response to the commands. Implement it to try
+/// another reading of the course.
+pub trait Submarine {
+    /// Moves as `command` says, failing if the position no longer fits.
+    fn execute(&mut self, command: &Command) -> Result<()>;
+    fn horizontal(&self) -> i64;
+    fn depth(&self) -> i64;
+
+    /// Where the submarine is pointing. Models without aim keep it at 0.
+    fn aim(&self) -> i64 {
+        0
+    }
+}
+
+/// The error for a course that takes a submarine further than an `i64`
+/// can count.
+fn overflow() -> AocError {
+    AocError::no_solution("the submarine's position overflows a 64-bit integer")
+}
+
+/// `a + b`, or the overflow error.
+fn add(a: i64, b: i64) -> Result<i64> {
+    a.checked_add(b).ok_or_else(overflow)
+}
+
+/// `a - b`, or the overflow error.
+fn sub(a: i64, b: i64) -> Result<i64> {
+    a.checked_sub(b).ok_or_else(overflow)
+}
+
+/// `a * b`, or the overflow error.
+fn mul(a: i64, b: i64) -> Result<i64> {
+    a.checked_mul(b).ok_or_else(overflow)
+}
+
+/// Moves forward, up and down exactly as commanded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DirectSubmarine {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+impl Submarine for DirectSubmarine {
+    fn execute(&mut self, command: &Command) -> Result<()> {
+        match *command {
+            Command::Forward(distance) => self.horizontal = add(self.horizontal, distance.into())?,
+            Command::Up(distance) => self.depth = sub(self.depth, distance.into())?,
+            Command::Down(distance) => self.depth = add(self.depth, distance.into())?,
+            Command::Back(distance) => self.horizontal = sub(self.horizontal, distance.into())?,
+        }
+        Ok(())
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+}
+
+/// Turns its aim with `up` and `down`, and dives along it when moving forward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AimedSubmarine {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Submarine for AimedSubmarine {
+    fn execute(&mut self, command: &Command) -> Result<()> {
+        match *command {
+            Command::Forward(distance) => {
+                self.horizontal = add(self.horizontal, distance.into())?;
+                self.depth = add(self.depth, mul(distance.into(), self.aim)?)?;
+            },
+            Command::Up(distance) => self.aim = sub(self.aim, distance.into())?,
+            Command::Down(distance) => self.aim = add(self.aim, distance.into())?,
+            Command::Back(distance) => {
+                self.horizontal = sub(self.horizontal, distance.into())?;
+                self.depth = sub(self.depth, mul(distance.into(), self.aim)?)?;
+            },
+        }
+        Ok(())
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn aim(&self) -> i64 {
+        self.aim
+    }
+}
+
+/// Where a submarine is after a command of the course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// The 1-based position of the command in the expanded course.
+    pub number: usize,
+    pub command: Command,
+    /// Where the command is written in the course file.
+    pub line: usize,
+    pub column: usize,
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+/// The state of `submarine` after each command of the course. An overflow
+/// is reported at the command that caused it.
+pub fn trajectory<S: Submarine>(mut submarine: S, course: &[Instruction]) -> Result<Vec<Step>> {
+    course.iter()
+          .enumerate()
+          .map(|(i, instruction)| {
+              submarine.execute(&instruction.command)
+                       .map_err(|err| err.at_column(instruction.column).at_line(instruction.line))?;
+              Ok(Step {
+                  number: i + 1,
+                  command: instruction.command,
+                  line: instruction.line,
+                  column: instruction.column,
+                  horizontal: submarine.horizontal(),
+                  depth: submarine.depth(),
+                  aim: submarine.aim(),
+              })
+          })
+          .collect()
+}
+
+/// Product of the final horizontal position and depth of `submarine` after
+/// following the course.
+pub fn destination_product<'a, S, C>(mut submarine: S, commands : C) -> Result<i64>
+    where S: Submarine, C: IntoIterator<Item = &'a Command> {
+    commands.into_iter().try_for_each(|command| submarine.execute(command))?;
+    mul(submarine.horizontal(), submarine.depth())?.checked_abs().ok_or_else(overflow)
+}
+
+/// Product of the final horizontal position and depth, moving directly.
+pub fn planned_course<'a, C: IntoIterator<Item = &'a Command>>(commands : C) -> Result<i64> {
+    destination_product(DirectSubmarine::default(), commands)
+}
+
+/// Product of the final horizontal position and depth, steering by aim.
+pub fn course_with_aim<'a, C: IntoIterator<Item = &'a Command>>(commands : C) -> Result<i64> {
+    destination_product(AimedSubmarine::default(), commands)
+}
+
+fn commands(course: &[Instruction]) -> impl Iterator<Item = &Command> {
+    course.iter().map(|instruction| &instruction.command)
+}
+
+pub type Input = Vec<Instruction>;
+
+/// Reads the planned course, written as described for `parse_course`.
+pub fn parse(path: &Path) -> Result<Input> {
+    parse_course(&input::lines(path)?).map_err(|err| err.in_file(path))
+}
+
+/// Horizontal position multiplied by depth after following the course directly.
+pub fn part1(course: &Input) -> Result<Answer> {
+    Ok(planned_course(commands(course))?.into())
+}
+
+/// Horizontal position multiplied by depth when `up` and `down` adjust the aim instead.
+pub fn part2(course: &Input) -> Result<Answer> {
+    Ok(course_with_aim(commands(course))?.into())
+}
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -1,60 +1,111 @@
 use std::env;
-use std::fs;
-use std::io;
-use std::io::prelude::*;
-
-fn get_file_contents(filename : &String) -> Vec<String> {
-    let file = fs::File::open(filename).expect("cannot open file");
-    io::BufReader::new(file).lines()
-                            .filter_map(|line| line.ok()?.parse::<String>().ok())
-                            .collect()
-}
-
-fn parse_instruction(instruction : &String) -> (i32, i32) {
-    let parts : Vec<&str> = instruction.split_whitespace().collect();
-    let mag = parts[1].parse::<i32>().unwrap();
-
-    match parts[0] {
-        "forward" => (mag, 0),
-        "up" => (0, -mag),
-        "down" => (0, mag),
-        _ => panic!(),
+use std::path::PathBuf;
+use std::process;
+
+use aoc_common::input;
+use day2::{AimedSubmarine, DirectSubmarine, Step};
+
+const USAGE: &str = "usage: day2 [--trajectory csv|json] [--model direct|aimed] [INPUT]
+reads stdin when INPUT is - or missing";
+
+/// What to print for the planned course.
+enum Output {
+    Answers,
+    Csv,
+    JsonLines,
+}
+
+/// Which submarine the trajectory follows.
+enum Model {
+    Direct,
+    Aimed,
+}
+
+struct Options {
+    output: Output,
+    model: Model,
+    path: PathBuf,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { output: Output::Answers, model: Model::Aimed, path: PathBuf::from(input::STDIN) };
+    let mut path_given = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().map(String::as_str).ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--trajectory" => options.output = match value()? {
+                "csv" => Output::Csv,
+                "json" => Output::JsonLines,
+                format => return Err(format!("trajectory format must be csv or json, not {:?}", format)),
+            },
+            "--model" => options.model = match value()? {
+                "direct" => Model::Direct,
+                "aimed" => Model::Aimed,
+                model => return Err(format!("model must be direct or aimed, not {:?}", model)),
+            },
+            _ if !path_given => {
+                options.path = PathBuf::from(arg);
+                path_given = true;
+            },
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
     }
+    Ok(options)
 }
 
-fn part1(instructions : &Vec<String>) {
-    let destination = instructions.iter()
-                                  .fold((0, 0), |coords, instruction| {
-                                      let action = parse_instruction(instruction);
-                                      (coords.0 + action.0, coords.1 + action.1)
-                                  });
+fn print_csv(trajectory: &[Step]) {
+    println!("step,line,column,command,horizontal,depth,aim");
+    for step in trajectory {
+        println!("{},{},{},{},{},{},{}",
+                 step.number, step.line, step.column, step.command, step.horizontal, step.depth, step.aim);
+    }
+}
 
-    println!("Part1");
-    println!("solution: {}", (destination.0 * destination.1).abs());
+fn print_json_lines(trajectory: &[Step]) {
+    for step in trajectory {
+        println!("{}", serde_json::json!({
+            "step": step.number,
+            "line": step.line,
+            "column": step.column,
+            "command": step.command.to_string(),
+            "horizontal": step.horizontal,
+            "depth": step.depth,
+            "aim": step.aim,
+        }));
+    }
 }
 
+fn run(options: &Options) -> aoc_common::Result<()> {
+    let commands = day2::parse(&options.path)?;
 
-fn part2(instructions : &Vec<String>) {
-    let destination = instructions.iter()
-                                  .fold((0, 0, 0), |coords, instruction| {
-                                      let action = parse_instruction(instruction);
-                                      (coords.0 + action.0,             // forward
-                                       coords.1 + action.0 * coords.2,  // depth 
-                                       coords.2 + action.1)             // aim
-                                  });
+    let trajectory = || match options.model {
+        Model::Direct => day2::trajectory(DirectSubmarine::default(), &commands),
+        Model::Aimed => day2::trajectory(AimedSubmarine::default(), &commands),
+    };
+    match options.output {
+        Output::Answers => {
+            println!("Part1");
+            println!("solution: {}", day2::part1(&commands)?);
 
-    println!("Part2");
-    println!("solution: {}", (destination.0 * destination.1).abs());
+            println!("Part2");
+            println!("solution: {}", day2::part2(&commands)?);
+        },
+        Output::Csv => print_csv(&trajectory()?),
+        Output::JsonLines => print_json_lines(&trajectory()?),
+    }
+    Ok(())
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    assert_eq!(args.len(), 2, "Need to provide an input file as a second argument. \
-                               Number of arguments is not 2");
-    
-    let instructions = get_file_contents(&args[1]);
-
-    part1(&instructions);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = parse_args(&args).unwrap_or_else(|message| {
+        eprintln!("error: {}\n{}", message, USAGE);
+        process::exit(2);
+    });
 
-    part2(&instructions);
+    if let Err(err) = run(&options) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
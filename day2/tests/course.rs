@@ -0,0 +1,87 @@
+use day2::{parse_course, Command, Instruction};
+
+fn parse_located(text: &str) -> aoc_common::Result<Vec<Instruction>> {
+    parse_course(&text.lines().map(str::to_string).collect::<Vec<_>>())
+}
+
+fn parse(text: &str) -> aoc_common::Result<Vec<Command>> {
+    parse_located(text).map(|course| course.into_iter().map(|instruction| instruction.command).collect())
+}
+
+fn error(text: &str) -> String {
+    parse(text).unwrap_err().to_string()
+}
+
+#[test]
+fn plain_courses_parse_as_before() {
+    assert_eq!(parse("forward 5\n\ndown 5\nup 3\n").unwrap(),
+               vec![Command::Forward(5), Command::Down(5), Command::Up(3)]);
+}
+
+#[test]
+fn repeats_macros_and_comments_expand() {
+    let course = "\
+# a dive and a climb
+macro dive {
+    down 2     # steeper
+    forward 1
+}
+repeat 2 { dive repeat 2 { up 1 } }
+back 3
+dive";
+    assert_eq!(parse(course).unwrap(),
+               vec![Command::Down(2), Command::Forward(1), Command::Up(1), Command::Up(1),
+                    Command::Down(2), Command::Forward(1), Command::Up(1), Command::Up(1),
+                    Command::Back(3),
+                    Command::Down(2), Command::Forward(1)]);
+}
+
+#[test]
+fn expanded_commands_keep_where_they_are_written() {
+    let course = parse_located("macro dive {\n  down 2\n}\nrepeat 2 { dive forward 1 }").unwrap();
+    let positions: Vec<_> = course.iter().map(|instruction| (instruction.line, instruction.column)).collect();
+    assert_eq!(positions, [(2, 3), (4, 17), (2, 3), (4, 17)]);
+}
+
+#[test]
+fn back_retraces_forward() {
+    let course = parse("down 4\nforward 6\nback 2").unwrap();
+    assert_eq!(day2::planned_course(&course).unwrap(), 4 * 4);
+    assert_eq!(day2::course_with_aim(&course).unwrap(), 4 * 16);
+}
+
+#[test]
+fn repeats_that_overflow_the_position_are_errors() {
+    let course = parse_located("repeat 2 { down 2000000000 forward 2000000000 }").unwrap();
+    // the direct product is 4e9 * 4e9, and the aimed depth passes 1.2e19 on the last forward
+    assert_eq!(day2::part1(&course).unwrap_err().to_string(),
+               "no solution: the submarine's position overflows a 64-bit integer");
+    assert!(day2::part2(&course).is_err());
+    let err = day2::trajectory(day2::AimedSubmarine::default(), &course).unwrap_err();
+    assert_eq!((err.line, err.column), (Some(1), Some(28)));
+
+    // three times i32::MAX still fits once the position is 64-bit
+    let course = parse_located("repeat 3 { forward 2000000000 }").unwrap();
+    assert_eq!(day2::part1(&course).unwrap().to_string(), "0");
+}
+
+#[test]
+fn errors_point_at_the_offending_token() {
+    assert_eq!(error("forward 1\n  sideways 2"),
+               "2:3: invalid token \"sideways\": expected forward, up, down, back, repeat, macro or a defined macro");
+    assert_eq!(error("forward x"), "1:9: invalid token \"x\": invalid digit found in string");
+    assert_eq!(error("repeat 2 forward 1"), "1:10: invalid token \"forward\": expected {");
+    assert_eq!(error("up 1\n}"), "2:1: invalid token \"}\": there is no block to close");
+    assert_eq!(error("repeat 2 {\n  up 1"),
+               "2:7: unexpected end of input, expected } closing the block opened at 1:10");
+    assert_eq!(error("repeat 2 { macro m { up 1 } }"),
+               "1:12: invalid token \"macro\": macros are defined at the top level");
+    assert_eq!(error("macro up { down 1 }"), "1:7: invalid token \"up\": expected a macro name");
+    assert_eq!(error("down"), "1:5: unexpected end of input, expected a distance");
+}
+
+#[test]
+fn courses_cannot_expand_without_bound() {
+    assert_eq!(error("repeat 100000 {\n repeat 100000 { up 1 } }"),
+               "1:1: the course expands to more than 10000000 commands");
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day2::Day2;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day2>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,75 @@
+use day2::{AimedSubmarine, Command, DirectSubmarine, Instruction, Submarine};
+
+/// Runs every command of the course on `submarine`.
+fn follow<S: Submarine>(submarine: &mut S, commands: &[Command]) {
+    commands.iter().for_each(|command| submarine.execute(command).unwrap());
+}
+
+const COURSE: [Command; 6] = [Command::Forward(5),
+                              Command::Down(5),
+                              Command::Forward(8),
+                              Command::Up(3),
+                              Command::Down(8),
+                              Command::Forward(2)];
+
+#[test]
+fn direct_submarine_moves_as_commanded() {
+    let mut submarine = DirectSubmarine::default();
+    follow(&mut submarine, &COURSE);
+    assert_eq!(submarine, DirectSubmarine { horizontal: 15, depth: 10 });
+}
+
+#[test]
+fn aimed_submarine_dives_along_its_aim() {
+    let mut submarine = AimedSubmarine::default();
+    follow(&mut submarine, &COURSE);
+    assert_eq!(submarine, AimedSubmarine { horizontal: 15, depth: 60, aim: 10 });
+}
+
+/// A third motion model: a submarine that cannot rise above the surface.
+#[derive(Default)]
+struct SurfacingSubmarine(DirectSubmarine);
+
+impl Submarine for SurfacingSubmarine {
+    fn execute(&mut self, command: &Command) -> aoc_common::Result<()> {
+        self.0.execute(command)?;
+        self.0.depth = self.0.depth.max(0);
+        Ok(())
+    }
+
+    fn horizontal(&self) -> i64 {
+        self.0.horizontal
+    }
+
+    fn depth(&self) -> i64 {
+        self.0.depth
+    }
+}
+
+#[test]
+fn new_models_only_implement_the_trait() {
+    let course = [Command::Down(1), Command::Up(3), Command::Down(2), Command::Forward(3)];
+    assert_eq!(day2::destination_product(SurfacingSubmarine::default(), &course).unwrap(), 6);
+    assert_eq!(day2::destination_product(DirectSubmarine::default(), &course).unwrap(), 0);
+}
+
+/// The course as if written one command per line.
+fn instructions() -> Vec<Instruction> {
+    COURSE.iter().enumerate().map(|(i, &command)| Instruction { command, line: i + 1, column: 1 }).collect()
+}
+
+#[test]
+fn trajectory_records_every_command() {
+    let trajectory = day2::trajectory(AimedSubmarine::default(), &instructions()).unwrap();
+    assert_eq!(trajectory.len(), COURSE.len());
+    assert_eq!(trajectory[2], day2::Step {
+        number: 3,
+        command: Command::Forward(8),
+        line: 3,
+        column: 1,
+        horizontal: 13,
+        depth: 40,
+        aim: 5,
+    });
+    assert_eq!(day2::trajectory(DirectSubmarine::default(), &instructions()).unwrap()[3].aim, 0);
+}
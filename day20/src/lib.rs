@@ -0,0 +1,96 @@
+//! Day 20: Trench Map
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+use aoc_common::grid::{Cell, Grid};
+
+pub struct Day20;
+
+pub type Image = Grid<bool>;
+
+fn binary_to_dec<'a>(binary: impl IntoIterator<Item = &'a bool>) -> usize {
+    binary.into_iter().fold(0, |dec, bit| dec << 1 ^ *bit as usize)
+}
+
+fn parse_pixels(line: &str) -> Result<Vec<bool>> {
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| bool::from_char(c).ok_or_else(||
+            AocError::invalid_token(c, "expected # or .").at_column(i + 1)))
+        .collect()
+}
+
+fn get_file_contents(filename: &Path) -> Result<(Vec<bool>, Image)> {
+    let mut width = None;
+    let (header, body) = input::header_and_body_with(filename, |line| {
+        let row = parse_pixels(line.trim())?;
+        match *width.get_or_insert(row.len()) {
+            w if w != row.len() => Err(AocError::format(format!("expected {} pixels, found {}", w, row.len()))),
+            _ => Ok(row),
+        }
+    })?;
+
+    let enhancement_line = parse_pixels(header.trim()).map_err(|err| err.at_line(1).in_file(filename))?;
+    if enhancement_line.len() != 512 {
+        return Err(AocError::format(format!("expected 512 pixels of enhancement algorithm, found {}",
+                                            enhancement_line.len()))
+                       .at_line(1)
+                       .in_file(filename));
+    }
+
+    Ok((enhancement_line, Grid::from_rows(body)))
+}
+
+/// Number of lit pixels after `iterations` enhancements.
+pub fn run(iterations: u32,
+       mut image: Image,
+       enhancement_line: &[bool]) -> u64 {
+
+    let mut pad_val = false;
+    for _ in 0..iterations {
+        let padded = image.pad(pad_val, 3);
+        image = Grid::filled(padded.rows(), padded.cols(), false);
+        for ((x, y), kernel) in padded.windows(3) {
+            image[(x, y)] = enhancement_line[binary_to_dec(kernel)];
+        }
+        pad_val = enhancement_line[binary_to_dec(&[pad_val; 9])];
+        image = image.trim(|&pixel| !pixel);
+    }
+
+    image.iter().filter(|&&pixel| pixel).count() as u64
+}
+
+pub type Input = (Vec<bool>, Image);
+
+/// Reads the image enhancement algorithm and the input image.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Lit pixels after enhancing the image twice.
+pub fn part1((enhancement_line, image): &Input) -> Result<Answer> {
+    Ok(run(2, image.clone(), enhancement_line).into())
+}
+
+/// Lit pixels after enhancing the image 50 times.
+pub fn part2((enhancement_line, image): &Input) -> Result<Answer> {
+    Ok(run(50, image.clone(), enhancement_line).into())
+}
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Trench Map";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
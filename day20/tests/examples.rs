@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day20::Day20;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day20>(env!("CARGO_MANIFEST_DIR"));
+}
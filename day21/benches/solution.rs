@@ -0,0 +1,11 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day21::Day21;
+
+fn solution(c: &mut Criterion) {
+    // the puzzle input is just two starting positions, so the example stands in for it
+    bench::bench_solution_on::<Day21>(c, env!("CARGO_MANIFEST_DIR"), "test_input");
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+//! Day 21: Dirac Dice
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day21;
+
+struct DeterministicDice {
+    value : u32
+}
+
+impl DeterministicDice {
+    fn roll(&mut self, times: u32) -> u32 {
+        (0..times).fold(0, |acc, _| { 
+            self.value = self.value % 100 + 1;
+            acc + self.value
+        })
+
+    }
+}
+
+/// Losing score multiplied by the number of rolls with the deterministic die.
+pub fn play_deterministic(mut player1: u32, mut player2: u32) -> u32 {
+    let mut dice = DeterministicDice { value: 0 };
+    let mut player1_score = 0;
+    let mut player2_score = 0;
+    let mut dice_rolls = 0;
+    loop { 
+        player1 = (player1 + dice.roll(3) - 1) % 10 + 1;
+        player1_score += player1;
+        dice_rolls += 3;
+        if player1_score >= 1000 { break; }
+
+        player2 = (player2 + dice.roll(3) - 1) % 10 + 1;
+        player2_score += player2;
+        dice_rolls += 3;
+        if player2_score >= 1000 { break; }
+    }
+
+    if player1_score > player2_score {
+        player2_score * dice_rolls
+    } else {
+        player1_score * dice_rolls
+    }
+
+}
+
+// 3 rolls of 3
+/// Number of ways three rolls of the Dirac die sum to `value`, zero for sums
+/// outside 3 to 9.
+pub fn ways_of_getting_dice_value(value: u32) -> u64 {
+    match value {
+        3 => 1,
+        4 => 3,
+        5 => 6,
+        6 => 7,
+        7 => 6,
+        8 => 3,
+        9 => 1,
+        _ => 0
+    }
+}
+
+/// Universes won by each player, playing to `score`, from the given positions
+/// and scores with player one to move.
+pub fn play_turn(score: u32, p1_state: u32, p2_state: u32, p1_score: u32, p2_score: u32) -> (u64, u64) {
+    let mut p1_wins : u64 = 0;
+    let mut p2_wins : u64 = 0;
+    // dice rolls can be 3-9
+    for p1_roll in 3..=9 {
+        let p1_new_state = (p1_state + p1_roll - 1) % 10 + 1;
+        let p1_new_score = p1_score + p1_new_state;
+        let routes_to_p1_new_state = ways_of_getting_dice_value(p1_roll);
+        if p1_new_score >= score {
+            p1_wins += routes_to_p1_new_state;
+            continue;
+        }
+        for p2_roll in 3..=9 {
+            let p2_new_state = (p2_state + p2_roll - 1) % 10 + 1;
+            let p2_new_score = p2_score + p2_new_state;
+            let routes_to_p2_new_state = routes_to_p1_new_state * ways_of_getting_dice_value(p2_roll);
+            if p2_new_score >= score {
+                p2_wins += routes_to_p2_new_state;
+                continue;
+            }
+            let wins = play_turn(
+                score, p1_new_state, p2_new_state, p1_new_score, p2_new_score);
+
+            p1_wins += routes_to_p2_new_state * wins.0;
+            p2_wins += routes_to_p2_new_state * wins.1;
+        }
+    }
+    (p1_wins, p2_wins)
+}
+
+/// Parses a starting position, which must be a space on the board: 1 to 10.
+pub fn parse_position(position: &str) -> Result<u32> {
+    match input::parse_token(position)? {
+        start @ 1..=10 => Ok(start),
+        _ => Err(AocError::invalid_token(position, "starting positions run from 1 to 10")),
+    }
+}
+
+fn get_starting_positions(filename: &Path) -> Result<(u32, u32)> {
+    let positions = input::parse_lines_with(filename, |line| {
+        let column = line.rfind(':').map_or(0, |colon| colon + 1);
+        let position = &line[column..];
+        parse_position(position.trim())
+            .map_err(|err| err.at_column(column + 1 + position.len() - position.trim_start().len()))
+    })?;
+
+    match positions[..] {
+        [player1, player2] => Ok((player1, player2)),
+        _ => Err(AocError::format(format!("expected 2 starting positions, found {}", positions.len()))
+                 .in_file(filename)),
+    }
+}
+
+pub type Input = (u32, u32);
+
+/// Reads the starting positions of both players.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_starting_positions(path)
+}
+
+/// Losing score multiplied by the number of die rolls with the deterministic die.
+pub fn part1((player1, player2): &Input) -> Result<Answer> {
+    Ok(play_deterministic(*player1, *player2).into())
+}
+
+/// Number of universes won by the player who wins in more universes with the Dirac die.
+pub fn part2((player1, player2): &Input) -> Result<Answer> {
+    let wins = play_turn(21, *player1, *player2, 0, 0);
+    Ok(std::cmp::max(wins.0, wins.1).into())
+}
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
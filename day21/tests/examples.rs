@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day21::Day21;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day21>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,293 @@
+//! Day 3: Binary Diagnostic
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FromIterator;
+use std::path::Path;
+use std::str::FromStr;
+use num_bigint::BigUint;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day3;
+
+/// A binary number of any width, stored as a bitset. Bits are indexed from
+/// the most significant, as they are written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bits {
+    /// 64 bits per word, least significant word first.
+    words: Vec<u64>,
+    width: usize,
+}
+
+impl Bits {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The bit `index` places from the most significant.
+    pub fn bit(&self, index: usize) -> bool {
+        assert!(index < self.width, "bit {} of a {} bit number", index, self.width);
+        let position = self.width - 1 - index;
+        self.words[position / 64] >> (position % 64) & 1 == 1
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.width).map(move |index| self.bit(index))
+    }
+
+    pub fn value(&self) -> BigUint {
+        self.words.iter().rev().fold(BigUint::default(), |value, &word| (value << 64u32) + word)
+    }
+}
+
+impl FromIterator<bool> for Bits {
+    /// Collects bits, most significant first.
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> Bits {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        let mut words = vec![0; bits.len().div_ceil(64)];
+        for (position, &bit) in bits.iter().rev().enumerate() {
+            words[position / 64] |= (bit as u64) << (position % 64);
+        }
+        Bits { words, width: bits.len() }
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter().try_for_each(|bit| write!(f, "{}", bit as u8))
+    }
+}
+
+impl FromStr for Bits {
+    type Err = AocError;
+
+    /// Parses a string of `0`s and `1`s, reporting any other character.
+    fn from_str(s: &str) -> Result<Bits> {
+        s.chars()
+         .enumerate()
+         .map(|(i, c)| match c {
+             '0' => Ok(false),
+             '1' => Ok(true),
+             _ => Err(AocError::invalid_token(c, "expected a binary digit, 0 or 1").at_column(i + 1)),
+         })
+         .collect()
+    }
+}
+
+/// Decides which bit a rating keeps, from how many of the numbers still in
+/// the running have a 0 and a 1 in the current position. Only consulted
+/// when both bits occur.
+pub trait BitRule {
+    fn keep(&self, zeros: usize, ones: usize) -> bool;
+}
+
+impl<F: Fn(usize, usize) -> bool> BitRule for F {
+    fn keep(&self, zeros: usize, ones: usize) -> bool {
+        self(zeros, ones)
+    }
+}
+
+/// Which bit wins when a position has as many 0s as 1s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    Zero,
+    One,
+}
+
+impl Tie {
+    fn bit(self) -> bool {
+        self == Tie::One
+    }
+}
+
+/// Picks the most or least common bit in a position, settling ties by an
+/// explicit policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitCriteria {
+    MostCommon(Tie),
+    LeastCommon(Tie),
+}
+
+impl BitCriteria {
+    /// The gamma rate's bits.
+    pub const GAMMA: BitCriteria = BitCriteria::MostCommon(Tie::One);
+    /// The epsilon rate's bits, the complement of gamma's.
+    pub const EPSILON: BitCriteria = BitCriteria::LeastCommon(Tie::Zero);
+    /// The oxygen generator rating's rule.
+    pub const OXYGEN: BitCriteria = BitCriteria::MostCommon(Tie::One);
+    /// The CO2 scrubber rating's rule.
+    pub const CO2: BitCriteria = BitCriteria::LeastCommon(Tie::Zero);
+
+    /// The bit chosen given how many 0s and 1s there are.
+    pub fn pick(self, zeros: usize, ones: usize) -> bool {
+        match self {
+            _ if zeros == ones => self.tie().bit(),
+            BitCriteria::MostCommon(_) => ones > zeros,
+            BitCriteria::LeastCommon(_) => ones < zeros,
+        }
+    }
+
+    fn tie(self) -> Tie {
+        match self {
+            BitCriteria::MostCommon(tie) | BitCriteria::LeastCommon(tie) => tie,
+        }
+    }
+}
+
+impl BitRule for BitCriteria {
+    fn keep(&self, zeros: usize, ones: usize) -> bool {
+        self.pick(zeros, ones)
+    }
+}
+
+/// The number made by picking a bit for every position from the whole
+/// report, as the gamma and epsilon rates are.
+pub fn rate(input : &[Bits], criteria : BitCriteria) -> Bits {
+    let bin_size = input.first().map_or(0, Bits::width);
+    let ones = input.iter()
+                    .fold(vec![0; bin_size], |acc, x| acc.iter()
+                                                        .zip(x.iter())
+                                                        .map(|(x1, x2)| x1 + x2 as usize)
+                                                        .collect());
+
+    ones.iter().map(|&ones| criteria.pick(input.len() - ones, ones)).collect()
+}
+
+/// The gamma rate multiplied by the epsilon rate.
+pub fn power_consumption(input : &[Bits]) -> BigUint {
+    let gamma = rate(input, BitCriteria::GAMMA);
+    let epsilon = rate(input, BitCriteria::EPSILON);
+    gamma.value() * epsilon.value()
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// How many numbers pass through this node.
+    count: usize,
+    children: [Option<usize>; 2],
+}
+
+/// The numbers of a report as a binary trie, built once, where each node
+/// knows how many numbers share its prefix.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    width: usize,
+}
+
+impl Trie {
+    /// Builds the trie of numbers that are all of the same width.
+    pub fn new(numbers: &[Bits]) -> Trie {
+        let width = numbers.first().map_or(0, Bits::width);
+        let mut nodes = vec![Node::default()];
+        for number in numbers {
+            let mut node = 0;
+            nodes[node].count += 1;
+            for bit in number.iter() {
+                node = match nodes[node].children[bit as usize] {
+                    Some(child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        nodes[node].children[bit as usize] = Some(nodes.len() - 1);
+                        nodes.len() - 1
+                    },
+                };
+                nodes[node].count += 1;
+            }
+        }
+        Trie { nodes, width }
+    }
+
+    /// How many numbers the trie holds.
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number left after narrowing the report down one bit at a time
+    /// with `rule`. Where every remaining number shares a bit, that bit is
+    /// kept. `None` if the trie is empty.
+    pub fn rating<R: BitRule>(&self, rule: &R) -> Option<Bits> {
+        if self.is_empty() {
+            return None;
+        }
+        let count = |child: Option<usize>| child.map_or(0, |child| self.nodes[child].count);
+        let mut node = 0;
+        let mut bits = Vec::with_capacity(self.width);
+        while bits.len() < self.width {
+            let [zeros, ones] = self.nodes[node].children;
+            let bit = match (count(zeros), count(ones)) {
+                (0, _) => true,
+                (_, 0) => false,
+                (zeros, ones) => rule.keep(zeros, ones),
+            };
+            node = self.nodes[node].children[bit as usize].unwrap();
+            bits.push(bit);
+        }
+        Some(bits.into_iter().collect())
+    }
+}
+
+/// The oxygen generator rating multiplied by the CO2 scrubber rating.
+pub fn life_support_rating(input : &[Bits]) -> BigUint {
+    let trie = Trie::new(input);
+    let o2_gen_value = trie.rating(&BitCriteria::OXYGEN).unwrap().value();
+    let co2_scrub_value = trie.rating(&BitCriteria::CO2).unwrap().value();
+    o2_gen_value * co2_scrub_value
+}
+
+/// Reports too wide for an `i64` answer are given in decimal text.
+fn to_answer(value: BigUint) -> Answer {
+    i64::try_from(&value).map(Answer::Number).unwrap_or_else(|_| Answer::Text(value.to_string()))
+}
+
+pub type Input = Vec<Bits>;
+
+/// Reads the diagnostic report, one binary number of any width per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    let mut width = None;
+    input::parse_lines_with(path, |line| {
+        let number: Bits = line.trim().parse()?;
+        match *width.get_or_insert(number.width()) {
+            w if w != number.width() => Err(AocError::format(format!("expected {} digits, found {}", w, number.width()))),
+            _ => Ok(number),
+        }
+    })
+}
+
+/// Power consumption: the gamma rate multiplied by the epsilon rate.
+pub fn part1(report: &Input) -> Result<Answer> {
+    if report.is_empty() {
+        return Err(AocError::no_solution("the diagnostic report is empty"));
+    }
+    Ok(to_answer(power_consumption(report)))
+}
+
+/// Life support rating: the oxygen generator rating multiplied by the CO2 scrubber rating.
+pub fn part2(report: &Input) -> Result<Answer> {
+    if report.is_empty() {
+        return Err(AocError::no_solution("the diagnostic report is empty"));
+    }
+    Ok(to_answer(life_support_rating(report)))
+}
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,41 @@
+use day3::{BitCriteria, Bits, Tie};
+
+fn report(numbers: &[&str]) -> Vec<Bits> {
+    numbers.iter().map(|number| number.parse().unwrap()).collect()
+}
+
+#[test]
+fn ties_follow_the_policy() {
+    assert!(BitCriteria::MostCommon(Tie::One).pick(2, 2));
+    assert!(!BitCriteria::MostCommon(Tie::Zero).pick(2, 2));
+    assert!(BitCriteria::LeastCommon(Tie::One).pick(2, 2));
+    assert!(!BitCriteria::LeastCommon(Tie::Zero).pick(2, 2));
+}
+
+#[test]
+fn clear_majorities_ignore_the_policy() {
+    for &tie in &[Tie::Zero, Tie::One] {
+        assert!(BitCriteria::MostCommon(tie).pick(1, 2));
+        assert!(!BitCriteria::MostCommon(tie).pick(2, 1));
+        assert!(!BitCriteria::LeastCommon(tie).pick(1, 2));
+        assert!(BitCriteria::LeastCommon(tie).pick(2, 1));
+    }
+}
+
+#[test]
+fn ties_are_settled_alike_for_odd_and_even_reports() {
+    // the second column ties in the even report and is won by 1 in the odd one
+    assert_eq!(day3::rate(&report(&["01", "10"]), BitCriteria::GAMMA).to_string(), "11");
+    assert_eq!(day3::rate(&report(&["01", "10", "11"]), BitCriteria::GAMMA).to_string(), "11");
+    assert_eq!(day3::rate(&report(&["01", "10"]), BitCriteria::MostCommon(Tie::Zero)).to_string(), "00");
+}
+
+#[test]
+fn epsilon_complements_gamma() {
+    let report = report(&["0110", "1010", "1100", "0011"]);
+    let gamma = day3::rate(&report, BitCriteria::GAMMA);
+    let epsilon = day3::rate(&report, BitCriteria::EPSILON);
+    // the first two columns tie, so this also checks the tie policies agree
+    assert_eq!((gamma.to_string(), epsilon.to_string()), ("1110".to_string(), "0001".to_string()));
+    assert!(gamma.iter().zip(epsilon.iter()).all(|(g, e)| g != e));
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day3::Day3;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day3>(env!("CARGO_MANIFEST_DIR"));
+}
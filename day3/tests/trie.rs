@@ -0,0 +1,41 @@
+use day3::{BitCriteria, Bits, Trie};
+
+const REPORT: [&str; 12] = ["00100", "11110", "10110", "10111", "10101", "01111",
+                            "00111", "11100", "10000", "11001", "00010", "01010"];
+
+fn trie() -> Trie {
+    let report: Vec<Bits> = REPORT.iter().map(|number| number.parse().unwrap()).collect();
+    Trie::new(&report)
+}
+
+#[test]
+fn example_ratings() {
+    let trie = trie();
+    assert_eq!(trie.len(), 12);
+    assert_eq!(trie.rating(&BitCriteria::OXYGEN).unwrap().to_string(), "10111");
+    assert_eq!(trie.rating(&BitCriteria::CO2).unwrap().to_string(), "01010");
+}
+
+#[test]
+fn rules_can_be_closures() {
+    let trie = trie();
+    // always preferring 0 follows the smallest number, always preferring 1 the largest
+    assert_eq!(trie.rating(&|_, _| false).unwrap().to_string(), "00010");
+    assert_eq!(trie.rating(&|_, _| true).unwrap().to_string(), "11110");
+}
+
+#[test]
+fn empty_reports_have_no_rating() {
+    let trie = Trie::new(&[]);
+    assert!(trie.is_empty());
+    assert!(trie.rating(&BitCriteria::OXYGEN).is_none());
+}
+
+#[test]
+fn duplicates_count_separately() {
+    let report: Vec<Bits> = ["011", "011", "100"].iter().map(|number| number.parse().unwrap()).collect();
+    let trie = Trie::new(&report);
+    assert_eq!(trie.len(), 3);
+    assert_eq!(trie.rating(&BitCriteria::OXYGEN).unwrap().to_string(), "011");
+    assert_eq!(trie.rating(&BitCriteria::CO2).unwrap().to_string(), "100");
+}
@@ -0,0 +1,32 @@
+use day3::Bits;
+
+const REPORT: [&str; 12] = ["00100", "11110", "10110", "10111", "10101", "01111",
+                            "00111", "11100", "10000", "11001", "00010", "01010"];
+
+/// The example report with 64 zero bits appended to every number.
+fn widened() -> Vec<Bits> {
+    REPORT.iter().map(|number| format!("{}{}", number, "0".repeat(64)).parse().unwrap()).collect()
+}
+
+#[test]
+fn bits_round_trip() {
+    let text = format!("1{}01", "0".repeat(70));
+    let bits: Bits = text.parse().unwrap();
+    assert_eq!(bits.width(), 73);
+    assert_eq!(bits.to_string(), text);
+    assert_eq!(bits.value().to_string(), ((1_u128 << 72) + 1).to_string());
+}
+
+#[test]
+fn wide_reports_do_not_overflow() {
+    let report = widened();
+    assert_eq!(day3::power_consumption(&report).to_string(), "74862120722606461961536585265367396384768");
+    assert_eq!(day3::life_support_rating(&report).to_string(), "78264944391815846596576159709306688634880");
+}
+
+#[test]
+fn only_binary_digits_are_accepted() {
+    assert_eq!("0120".parse::<Bits>().unwrap_err().to_string(),
+               "invalid token \"2\": expected a binary digit, 0 or 1");
+    assert_eq!("01 0".parse::<Bits>().unwrap_err().column, Some(3));
+}
@@ -0,0 +1,250 @@
+//! Day 4: Giant Squid
+use std::collections::HashMap;
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day4;
+
+/// The drawn numbers and every board.
+pub struct Bingo {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<Board>,
+}
+
+/// A bingo card of any size, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    cells: Vec<u32>,
+    width: usize,
+    height: usize,
+}
+
+impl Board {
+    /// Builds a board from its rows, which must all have the same, non-zero length.
+    pub fn from_rows(rows: &[Vec<u32>]) -> Result<Board> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(AocError::format("a board needs at least one number"));
+        }
+        if let Some(row) = rows.iter().find(|row| row.len() != width) {
+            return Err(AocError::format(format!("expected {} numbers in a board row, found {}", width, row.len())));
+        }
+        Ok(Board { cells: rows.concat(), width, height: rows.len() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The numbers on the board, row by row.
+    pub fn cells(&self) -> &[u32] {
+        &self.cells
+    }
+}
+
+/// A board in play: which of its cells are marked, and how many marks each
+/// row and column has, so a win shows up the moment a count fills.
+#[derive(Debug, Clone)]
+pub struct Card<'a> {
+    board: &'a Board,
+    marked: Vec<bool>,
+    row_marks: Vec<usize>,
+    col_marks: Vec<usize>,
+    unmarked_sum: u64,
+    won: bool,
+}
+
+impl<'a> Card<'a> {
+    pub fn new(board: &'a Board) -> Card<'a> {
+        Card {
+            board,
+            marked: vec![false; board.cells.len()],
+            row_marks: vec![0; board.height],
+            col_marks: vec![0; board.width],
+            unmarked_sum: board.cells.iter().copied().map(u64::from).sum(),
+            won: false,
+        }
+    }
+
+    /// Marks the cell at `index` into the board's cells. True if this mark
+    /// is the one that completes the card's first row or column.
+    pub fn mark(&mut self, index: usize) -> bool {
+        if self.marked[index] {
+            return false;
+        }
+        self.marked[index] = true;
+        self.unmarked_sum -= u64::from(self.board.cells[index]);
+
+        let (row, col) = (index / self.board.width, index % self.board.width);
+        self.row_marks[row] += 1;
+        self.col_marks[col] += 1;
+        let completes = self.row_marks[row] == self.board.width || self.col_marks[col] == self.board.height;
+        let first_win = completes && !self.won;
+        self.won |= completes;
+        first_win
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.won
+    }
+
+    /// Sum of the unmarked numbers multiplied by the number that won, wide
+    /// enough that no board of `u32` numbers can overflow it.
+    pub fn score(&self, final_number: u32) -> u128 {
+        u128::from(self.unmarked_sum) * u128::from(final_number)
+    }
+}
+
+/// Every board in play, with an index from each number to the cells it
+/// appears in, so a draw only touches the cards that hold it.
+pub struct Game<'a> {
+    cards: Vec<Card<'a>>,
+    cells_by_number: HashMap<u32, Vec<(usize, usize)>>,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(boards: &'a [Board]) -> Game<'a> {
+        let mut cells_by_number: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+        for (board_index, board) in boards.iter().enumerate() {
+            for (cell, &number) in board.cells.iter().enumerate() {
+                cells_by_number.entry(number).or_default().push((board_index, cell));
+            }
+        }
+        Game { cards: boards.iter().map(Card::new).collect(), cells_by_number }
+    }
+
+    pub fn cards(&self) -> &[Card<'a>] {
+        &self.cards
+    }
+
+    /// Marks `number` everywhere it appears and returns the boards that won
+    /// because of it, in board order.
+    pub fn draw(&mut self, number: u32) -> Vec<usize> {
+        let mut winners = vec![];
+        if let Some(cells) = self.cells_by_number.get(&number) {
+            for &(board, cell) in cells {
+                if self.cards[board].mark(cell) {
+                    winners.push(board);
+                }
+            }
+        }
+        winners
+    }
+}
+
+/// A board completing its first row or column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub number: u32,
+    /// Which draw won it, counting from 1.
+    pub turn: usize,
+    pub score: u128,
+}
+
+/// How a whole game played out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// Every board that won, in the order they won. Boards winning on the
+    /// same draw are in board order.
+    pub winners: Vec<Win>,
+    /// The boards still without a win once the numbers run out.
+    pub never_won: Vec<usize>,
+}
+
+/// Plays every drawn number, stopping early once all the boards have won.
+pub fn simulate(numbers : &[u32], boards : &[Board]) -> Outcome {
+    let mut game = Game::new(boards);
+    let mut winners = vec![];
+    for (turn, &number) in numbers.iter().enumerate() {
+        if winners.len() == boards.len() {
+            break;
+        }
+        for board in game.draw(number) {
+            winners.push(Win { board, number, turn: turn + 1, score: game.cards()[board].score(number) });
+        }
+    }
+    let never_won = (0..boards.len()).filter(|&board| !game.cards()[board].has_won()).collect();
+    Outcome { winners, never_won }
+}
+
+fn parse_row(line : &str) -> Result<Vec<u32>> {
+    input::tokens(line).map(|(column, token)| input::parse_token(token).map_err(|err| err.at_column(column)))
+                       .collect()
+}
+
+/// Reads the drawn numbers from the first line, then the boards, which are
+/// separated by blank lines. Each board's size is taken from its rows.
+pub fn parse_bingo(input : &[String]) -> Result<Bingo> {
+    let mut sections = input::split_sections(input).into_iter();
+    let header = sections.next().unwrap_or_default();
+    let (header_line, numbers) = match header[..] {
+        [(line, numbers)] => (line, numbers),
+        [] => return Err(AocError::unexpected_eof("the drawn numbers")),
+        [_, (line, _), ..] => return Err(AocError::format("expected a blank line after the drawn numbers").at_line(line)),
+    };
+    let numbers = input::separated(numbers, ',')
+        .map(|(column, token)| input::parse_token(token).map_err(|err| err.at_column(column)))
+        .collect::<Result<Vec<u32>>>()
+        .map_err(|err| err.at_line(header_line))?;
+
+    let mut boards = vec![];
+    for section in sections {
+        let mut rows: Vec<Vec<u32>> = vec![];
+        for (number, line) in section {
+            let row = parse_row(line).map_err(|err| err.at_line(number))?;
+            if let Some(first) = rows.first().filter(|first| first.len() != row.len()) {
+                return Err(AocError::format(format!("expected {} numbers in a board row, found {}", first.len(), row.len()))
+                    .at_line(number));
+            }
+            rows.push(row);
+        }
+        boards.push(Board::from_rows(&rows)?);
+    }
+
+    Ok(Bingo { numbers, boards })
+}
+
+pub type Input = Bingo;
+
+/// Reads the drawn numbers followed by the bingo boards, as described for `parse_bingo`.
+pub fn parse(path: &Path) -> Result<Input> {
+    parse_bingo(&input::lines(path)?).map_err(|err| err.in_file(path))
+}
+
+/// Score of the first board to win.
+pub fn part1(bingo: &Input) -> Result<Answer> {
+    let outcome = simulate(&bingo.numbers, &bingo.boards);
+    let win = outcome.winners.first().ok_or_else(|| AocError::no_solution("no board wins"))?;
+    Ok(win.score.into())
+}
+
+/// Score of the last board to win.
+pub fn part2(bingo: &Input) -> Result<Answer> {
+    let outcome = simulate(&bingo.numbers, &bingo.boards);
+    let win = outcome.winners.last().ok_or_else(|| AocError::no_solution("no board wins"))?;
+    Ok(win.score.into())
+}
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,68 @@
+use day4::{Board, Card, Game};
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+#[test]
+fn sizes_are_taken_from_the_input() {
+    let bingo = day4::parse_bingo(&lines("1,2,3\n\n1 2 3\n4 5 6\n7 8 9\n\n1 2\n3 4\n5 6\n")).unwrap();
+    let sizes: Vec<_> = bingo.boards.iter().map(|board| (board.width(), board.height())).collect();
+    assert_eq!(sizes, [(3, 3), (2, 3)]);
+    assert_eq!(bingo.boards[1].cells(), [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn three_by_three_game() {
+    let bingo = day4::parse_bingo(&lines("5,1,11,16,2\n\n1 2 3\n4 5 6\n7 8 9\n\n10 11 12\n13 5 14\n15 16 17")).unwrap();
+    // the second board completes its middle column on 16, leaving 10 + 12 + 13 + 14 + 15 + 17
+    assert_eq!(day4::part1(&bingo).unwrap().to_string(), (81 * 16).to_string());
+}
+
+#[test]
+fn seven_by_seven_columns_win() {
+    let rows: Vec<Vec<u32>> = (0..7).map(|row| (0..7).map(|col| row * 7 + col).collect()).collect();
+    let board = Board::from_rows(&rows).unwrap();
+    let mut card = Card::new(&board);
+    let last_column: Vec<usize> = (6..49).step_by(7).collect();
+    assert!(last_column[..6].iter().all(|&cell| !card.mark(cell)));
+    assert!(!card.has_won());
+    assert!(card.mark(last_column[6]));
+    // only the first completed line counts as the win
+    assert!((14..21).all(|cell| !card.mark(cell)));
+    assert!(card.has_won());
+}
+
+#[test]
+fn wide_boards_win_on_rows_of_their_width() {
+    let board = Board::from_rows(&[vec![1, 2, 3, 4], vec![5, 6, 7, 8]]).unwrap();
+    let mut card = Card::new(&board);
+    assert!((0..3).all(|cell| !card.mark(cell)));
+    assert!(card.mark(3));
+    assert_eq!(card.score(4), (5 + 6 + 7 + 8) * 4);
+    // a column only needs two marks
+    let mut card = Card::new(&board);
+    assert!(!card.mark(2));
+    assert!(card.mark(6));
+}
+
+#[test]
+fn draws_mark_every_occurrence() {
+    let boards = [Board::from_rows(&[vec![1, 1], vec![2, 3]]).unwrap(),
+                  Board::from_rows(&[vec![4, 5], vec![1, 6]]).unwrap()];
+    let mut game = Game::new(&boards);
+    // the first board's top row is all ones; the second board's 1 stands alone
+    assert_eq!(game.draw(1), [0]);
+    assert!(game.draw(7).is_empty());
+    assert_eq!(game.draw(4), [1]);
+    assert_eq!(game.cards()[1].score(4), 11 * 4);
+}
+
+#[test]
+fn ragged_boards_are_rejected() {
+    let err = day4::parse_bingo(&lines("1\n\n1 2 3\n4 5\n")).err().unwrap();
+    assert_eq!(err.line, Some(4));
+    assert!(err.to_string().contains("expected 3 numbers in a board row, found 2"), "{}", err);
+    assert!(Board::from_rows(&[vec![1, 2], vec![3]]).is_err());
+    assert!(Board::from_rows(&[]).is_err());
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day4::Day4;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day4>(env!("CARGO_MANIFEST_DIR"));
+}
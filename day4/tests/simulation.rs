@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use day4::{Board, Win};
+
+#[test]
+fn example_winners_in_order() {
+    let bingo = day4::parse(&Path::new(env!("CARGO_MANIFEST_DIR")).join("test_input")).unwrap();
+    let outcome = day4::simulate(&bingo.numbers, &bingo.boards);
+    assert_eq!(outcome.winners, [Win { board: 2, number: 24, turn: 12, score: 4512 },
+                                 Win { board: 0, number: 16, turn: 14, score: 2192 },
+                                 Win { board: 1, number: 13, turn: 15, score: 1924 }]);
+    assert!(outcome.never_won.is_empty());
+}
+
+#[test]
+fn boards_that_never_win_are_reported() {
+    let boards = [Board::from_rows(&[vec![1, 2], vec![3, 4]]).unwrap(),
+                  Board::from_rows(&[vec![5, 6], vec![7, 8]]).unwrap(),
+                  Board::from_rows(&[vec![3, 9], vec![1, 9]]).unwrap()];
+    let outcome = day4::simulate(&[1, 3, 5], &boards);
+    // the first and last boards both complete a column on 3
+    assert_eq!(outcome.winners.iter().map(|win| (win.board, win.turn)).collect::<Vec<_>>(), [(0, 2), (2, 2)]);
+    assert_eq!(outcome.never_won, [1]);
+}
+
+#[test]
+fn games_without_winners_have_no_answers() {
+    let bingo = day4::parse_bingo(&["1,2".to_string(), String::new(), "1 2 3".to_string(), "4 5 6".to_string()]).unwrap();
+    assert_eq!(day4::simulate(&bingo.numbers, &bingo.boards).never_won, [0]);
+    assert!(day4::part1(&bingo).is_err());
+    assert!(day4::part2(&bingo).is_err());
+}
+
+#[test]
+fn big_numbers_do_not_overflow_the_score() {
+    let lines = |text: &str| text.lines().map(String::from).collect::<Vec<_>>();
+
+    let bingo = day4::parse_bingo(&lines("3000000000\n\n3000000000 3000000000")).unwrap();
+    assert_eq!(day4::part1(&bingo).unwrap().to_string(), "0");
+
+    let bingo = day4::parse_bingo(&lines("5\n\n3000000000 5")).unwrap();
+    assert_eq!(day4::part1(&bingo).unwrap().to_string(), "15000000000");
+
+    // the left column wins, leaving a score past u64::MAX
+    let bingo = day4::parse_bingo(&lines("4000000000\n\n4000000000 4000000001\n4000000000 4000000002")).unwrap();
+    assert_eq!(day4::simulate(&bingo.numbers, &bingo.boards).winners[0].score, 8_000_000_003 * 4_000_000_000);
+}
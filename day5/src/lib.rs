@@ -0,0 +1,117 @@
+//! Day 5: Hydrothermal Venture
+use std::path::Path;
+use std::collections::HashMap;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day5;
+
+#[derive(Hash, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x : u32,
+    pub y : u32
+}
+
+/// A vent line from its first point to its second.
+pub type Line = (Point, Point);
+
+fn parse_point(point_string : &str) -> Result<Point> {
+    let coords = input::separated(point_string, ',')
+        .map(|(column, token)| input::parse_token(token).map_err(|err| err.at_column(column)))
+        .collect::<Result<Vec<u32>>>()?;
+    match coords[..] {
+        [x, y] => Ok(Point { x, y }),
+        _ => Err(AocError::invalid_token(point_string, "expected a point such as 0,9")),
+    }
+}
+
+fn parse_line(line : &str) -> Result<Line> {
+    let (start, end) = line.split_once(" -> ")
+                           .ok_or_else(|| AocError::format("expected a line such as 0,9 -> 5,9"))?;
+    let vent = (parse_point(start).map_err(|err| err.at_token(1))?,
+                parse_point(end).map_err(|err| err.at_token(start.len() + 5))?);
+
+    let (dx, dy) = (vent.0.x.abs_diff(vent.1.x), vent.0.y.abs_diff(vent.1.y));
+    if dx != 0 && dy != 0 && dx != dy {
+        return Err(AocError::format("lines must be horizontal, vertical or at 45 degrees"));
+    }
+    Ok(vent)
+}
+
+fn get_file_contents(filename : &Path) -> Result<Vec<Line>> {
+    input::parse_lines_with(filename, parse_line)
+}
+
+/// Whether the line is horizontal or vertical.
+pub fn is_on_axis(line : &Line) -> bool {
+    line.0.x == line.1.x || line.0.y == line.1.y
+}
+
+fn linear_range(start : u32, finish : u32) -> Vec<u32> {
+    if finish >= start {
+        (start..=finish).collect()
+    }
+    else {
+        (finish..=start).rev().collect()
+    }
+}
+
+/// Every point on a horizontal, vertical or 45 degree line, ends included.
+pub fn interpolate(line: &Line) -> Vec<Point> {
+    let xs = linear_range(line.0.x, line.1.x);
+    let ys = linear_range(line.0.y, line.1.y);
+    if xs.len() == 1 {
+        return ys.iter().map(|y| Point { x: line.0.x, y: *y}).collect();
+    }
+    else if ys.len() == 1 {
+        return xs.iter().map(|x| Point { x: *x, y: line.0.y}).collect();
+    }
+    assert!(xs.len() == ys.len());
+    xs.iter().zip(ys).map(|(x, y)| Point { x: *x, y }).collect()
+}
+
+/// Number of points occupied more than once.
+pub fn get_intersections(line_occupancies : impl Iterator<Item=Point>) -> usize {
+    line_occupancies.fold(HashMap::new(), |mut map, point| {
+                                    let point_count = map.entry(point).or_insert(0);
+                                    *point_count += 1;
+                                    map
+                                })
+                    .iter()
+                    .fold(0, |acc, (_, count)| acc + if count > &1 { 1 } else { 0 })
+}
+
+pub type Input = Vec<Line>;
+
+/// Reads the vent lines, one `x1,y1 -> x2,y2` per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// Points where at least two horizontal or vertical lines overlap.
+pub fn part1(lines: &Input) -> Result<Answer> {
+    Ok(get_intersections(lines.iter().filter(|line| is_on_axis(line)).flat_map(interpolate)).into())
+}
+
+/// Points where at least two lines overlap, diagonals included.
+pub fn part2(lines: &Input) -> Result<Answer> {
+    Ok(get_intersections(lines.iter().flat_map(interpolate)).into())
+}
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day5::Day5;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day5>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,57 @@
+//! Day 6: Lanternfish
+use std::path::Path;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day6;
+
+/// Population after `days`, given fish counts bucketed by timer value.
+pub fn final_population(days : u32, mut state : Vec<u64>) -> u64 {
+    for _ in 0..days {
+        let lanternfish_births = state[0];
+        state.rotate_left(1);
+        state[6] += lanternfish_births;
+    }
+    state.iter().sum::<u64>()
+}
+
+pub type Input = Vec<u64>;
+
+/// Reads the initial lanternfish timers and buckets them by days until spawning.
+pub fn parse(path: &Path) -> Result<Input> {
+    let init_state : Vec<usize> = input::comma_separated_with(path, |token| match input::parse_token(token)? {
+        timer @ 0..=8 => Ok(timer),
+        _ => Err(AocError::invalid_token(token, "timers run from 0 to 8")),
+    })?;
+    let mut state = vec![0; 9]; // maximum of nine days
+    init_state.iter().for_each(|&day| state[day] += 1);
+    Ok(state)
+}
+
+/// Number of lanternfish after 80 days.
+pub fn part1(state: &Input) -> Result<Answer> {
+    Ok(final_population(80, state.clone()).into())
+}
+
+/// Number of lanternfish after 256 days.
+pub fn part2(state: &Input) -> Result<Answer> {
+    Ok(final_population(256, state.clone()).into())
+}
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day6::Day6;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day6>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,65 @@
+//! Day 7: The Treachery of Whales
+use std::path::Path;
+use aoc_common::{input, Answer, Result, Solution};
+
+pub struct Day7;
+
+/// Fuel needed to move `distance` when each step costs one more than the last.
+pub fn fuel_cost(distance : i32) -> i32 { 
+    distance * (1 + distance)/2
+}
+
+/// Least total fuel needed to align every crab, using `cost` per crab.
+pub fn alignment_cost(crab_positions : &[i32], cost : fn(i32) -> i32) -> i32 {
+    let min_pos = crab_positions.iter().min().unwrap();
+    let max_pos = crab_positions.iter().max().unwrap();
+
+    // Destination D, start position X, movement m
+    // D will lie between Xmin and Xmax
+    // Xi + mi = D
+    // D - Xi = mi
+    let alignment_costs : Vec<i32> = 
+        (*min_pos..=*max_pos).map(|d : i32| 
+                                  crab_positions.iter()
+                                                .map(|x| cost((d - x).abs()))
+                                                .sum())
+                             .collect();
+
+    *alignment_costs.iter().min().unwrap()
+}
+
+pub type Input = Vec<i32>;
+
+/// Reads the horizontal crab positions.
+pub fn parse(path: &Path) -> Result<Input> {
+    input::comma_separated(path)
+}
+
+/// Least fuel needed to align the crabs when each step costs one unit.
+pub fn part1(crab_positions: &Input) -> Result<Answer> {
+    Ok(alignment_cost(crab_positions, |distance| distance).into())
+}
+
+/// Least fuel needed to align the crabs when each further step costs one more unit than the last.
+pub fn part2(crab_positions: &Input) -> Result<Answer> {
+    Ok(alignment_cost(crab_positions, fuel_cost).into())
+}
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
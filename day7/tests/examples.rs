@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day7::Day7;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day7>(env!("CARGO_MANIFEST_DIR"));
+}
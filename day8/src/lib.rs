@@ -0,0 +1,207 @@
+//! Day 8: Seven Segment Search
+use std::path::Path;
+use std::collections::HashMap;
+use aoc_common::{input, AocError, Answer, Result, Solution};
+
+pub struct Day8;
+
+pub type Entry = (Vec<String>, Vec<String>);
+
+fn parse_patterns(patterns : &str, count : usize) -> Result<Vec<String>> {
+    let patterns = input::tokens(patterns)
+        .map(|(column, pattern)| convert_to_binary_rep(pattern).map(|_| pattern.to_string())
+                                                               .map_err(|err| err.at_token(column)))
+        .collect::<Result<Vec<String>>>()?;
+    if patterns.len() != count {
+        return Err(AocError::format(format!("expected {} patterns, found {}", count, patterns.len())));
+    }
+    Ok(patterns)
+}
+
+fn parse_entry(line : &str) -> Result<Entry> {
+    let (signals, output) = line.split_once('|')
+                                .ok_or_else(|| AocError::format("expected the signal patterns and output split by |"))?;
+    Ok((parse_patterns(signals, 10)?,
+        parse_patterns(output, 4).map_err(|err| err.at_token(signals.len() + 2))?))
+}
+
+fn get_file_contents(filename : &Path) -> Result<Vec<Entry>> {
+    input::parse_lines_with(filename, parse_entry)
+}
+
+/// Counts the output digits drawn with a unique number of segments.
+pub fn part_1(entries : &[Entry]) -> u32 {
+    entries.iter().fold(0, |acc, entry| 
+                     {
+                        acc + entry.1.iter().fold(0, |acc, digit_str|
+                                                  {
+                                                      let len = digit_str.len();
+                                                      acc + if len <= 4 || len == 7 { 1 } else {0}
+                                                  })
+                     })
+}
+
+// 1, 4, 7, 8 we get for free
+// convert letters to binary
+// in the example:
+//    a b c d e f g
+// 0: 1 1 1 0 1 1 1 
+// 1: 0 0 1 0 0 1 0 
+// 2: 1 0 1 1 1 0 1 
+// 3: 1 0 1 1 0 1 1
+// 4: 0 1 1 1 0 1 0
+// 5: 1 1 0 1 0 1 1
+// 6: 1 1 0 1 1 1 1
+// 7: 1 0 1 0 0 1 0
+// 8: 1 1 1 1 1 1 1
+// 9: 1 1 1 1 0 1 1
+// T: 8 6 8 7 4 9 7
+
+// Find the most common bit (9), the only one with the bit missing is 2
+// Find the least common bit (4), 8 AND NOT this bit is 9
+// Find the bit with count 6, 9 AND NOT this bit is 3
+// 0, 5, 6 remain - 5 is the one with 5 active bits
+// 5 OR most common bit is 6
+// 0 remains
+
+/// A signal pattern as a bitmask, from `a` in bit 6 down to `g` in bit 0.
+pub fn convert_to_binary_rep(input: &str) -> Result<u8> {
+    input.chars().enumerate().try_fold(0,|acc, (i, x)| match x
+                       {
+                         'a' => Ok(acc | 0b01000000),
+                         'b' => Ok(acc | 0b00100000),
+                         'c' => Ok(acc | 0b00010000),
+                         'd' => Ok(acc | 0b00001000),
+                         'e' => Ok(acc | 0b00000100),
+                         'f' => Ok(acc | 0b00000010),
+                         'g' => Ok(acc | 0b00000001),
+                         _ => Err(AocError::invalid_token(x, "segments run from a to g").at_column(i + 1))
+                       })
+}
+
+fn get_bit_counts(input: &[u8], bit_letters : &[u8]) -> Vec<usize> {
+    bit_letters.iter()
+               .map(|letter| input.iter().filter(|comp| letter & *comp > 0).count() )
+               .collect()
+}
+
+fn count_set_bits(mut number : u8) -> i32 {
+    let mut count: i32 = 0;
+    while number > 0 { 
+        count += (number & 1) as i32;
+        number >>= 1;
+    }
+    count
+}
+
+/// Maps each signal pattern bitmask of an entry to the digit it shows.
+pub fn determine_mapping(sequence: &[String]) -> Result<HashMap<u8, i32>> {
+    let undecodable = || AocError::no_solution(format!("cannot decode the patterns {}", sequence.join(" ")));
+    let mut sequence_as_binary : Vec<u8> = sequence.iter()
+                                               .map(|input| convert_to_binary_rep(input))
+                                               .collect::<Result<_>>()?;
+    let mut map = HashMap::new();
+    sequence_as_binary.iter()
+                      .for_each(|input| match count_set_bits(*input) {
+                        2 => { map.insert(*input, 1);},
+                        4 => { map.insert(*input, 4);},
+                        3 => { map.insert(*input, 7);},
+                        7 => { map.insert(*input, 8);},
+                        _ => ()
+            });
+
+    let bit_letters : Vec<u8> = (0..7).rev().map(|bit| 1 << bit).collect();
+
+    let bit_counts = get_bit_counts(&sequence_as_binary, &bit_letters);
+
+    // Find the most common bit (9), the only one with the bit missing is 2
+    let most_common_bit = bit_letters[bit_counts.iter().position(|x| *x == 9).ok_or_else(undecodable)?];
+    map.insert(*sequence_as_binary.iter().find(|&number| number & most_common_bit == 0).ok_or_else(undecodable)?, 2);
+
+    // Find the least common bit (4), 8 AND NOT this bit is 9
+    let least_common_bit = bit_letters[bit_counts.iter().position(|x| *x == 4).ok_or_else(undecodable)?];
+    let eight : &u8 = map.iter()
+                         .find_map(|(key, &value)| if value == 8 { Some(key) } else { None }).ok_or_else(undecodable)?;
+
+    let nine = eight & !least_common_bit;
+    map.insert(nine, 9);
+
+    // Find the bit with count 6, 9 not this bit is 3
+    let middle_bit = bit_letters[bit_counts.iter().position(|x| *x == 6).ok_or_else(undecodable)?];
+    let three = nine & !middle_bit;
+    map.insert(three, 3);
+
+    // 0, 5, 6 remain - 5 is the one with 5 active bits
+    sequence_as_binary.retain(|x| !map.contains_key(x));
+    if sequence_as_binary.len() != 3 {
+        return Err(undecodable());
+    }
+
+    let five = sequence_as_binary.iter().find(|&x| count_set_bits(*x) == 5).ok_or_else(undecodable)?;
+    map.insert(*five, 5);
+
+    // 5 OR least common bit is 6
+    let six = five | least_common_bit;
+    map.insert(six, 6);
+
+    // 0 remains
+    sequence_as_binary.retain(|x| !map.contains_key(x));
+    if sequence_as_binary.len() != 1 {
+        return Err(undecodable());
+    }
+
+    map.insert(sequence_as_binary[0], 0);
+
+    Ok(map)
+}
+
+/// Sum of every decoded output value.
+pub fn part_2(entries : &[Entry]) -> Result<i32> {
+    entries.iter()
+           .try_fold(0, |total, (input, output)|
+                 {
+                    let mapping = determine_mapping(input)?;
+                    Ok(total + output.iter().try_fold(0, |value, digit| {
+                        let segments = convert_to_binary_rep(digit)?;
+                        let decoded = mapping.get(&segments).ok_or_else(||
+                            AocError::no_solution(format!("output {} matches none of the patterns", digit)))?;
+                        Ok(value * 10 + decoded)
+                    })?)
+                })
+}
+
+pub type Input = Vec<Entry>;
+
+/// Reads the notes, ten signal patterns and four output digits per line.
+pub fn parse(path: &Path) -> Result<Input> {
+    get_file_contents(path)
+}
+
+/// How many output digits are a 1, 4, 7 or 8.
+pub fn part1(entries: &Input) -> Result<Answer> {
+    Ok(part_1(entries).into())
+}
+
+/// Sum of the decoded four digit output values.
+pub fn part2(entries: &Input) -> Result<Answer> {
+    Ok(part_2(entries)?.into())
+}
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
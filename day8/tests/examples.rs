@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day8::Day8;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day8>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,105 @@
+//! Day 9: Smoke Basin
+use std::path::Path;
+use aoc_common::{input, Answer, Result, Solution};
+use aoc_common::grid::{Coord, Grid};
+
+pub struct Day9;
+
+type Mask = Grid<bool>;
+
+fn get_unmarked_adjacents(coord : Coord, mask : &Mask) -> Vec<Coord> {
+    mask.neighbours4(coord)
+        .filter(|&adj| !mask[adj])
+        .collect()
+}
+
+/// Mask of the points lower than all of their neighbours.
+pub fn find_low_points(input : &Grid<u32>) -> Mask {
+    Grid::new(input.rows(),
+              input.cols(),
+              input.coords()
+                   .map(|coord| input.neighbours4(coord)
+                                     .all(|adj| input[coord] < input[adj]))
+                   .collect())
+}
+
+/// Sum of one plus the height of every low point.
+pub fn risk_level_sum(input : &Grid<u32>) -> u32 {
+    input.iter()
+         .zip(find_low_points(input).iter())
+         .filter_map(|(value, &is_low)| if is_low { Some(1 + *value) } else { None })
+         .sum()
+}
+
+/// The size of each basin, one per low point.
+pub fn basin_sizes(input : &Grid<u32>) -> Vec<usize> {
+    let mut masks : Vec<Mask> = find_low_points(input).enumerate()
+                                                       .filter_map(|(coord, &value)|
+                                                                   if value {
+                                                                       let mut mask = Grid::filled(input.rows(), input.cols(), false);
+                                                                       mask[coord] = true;
+                                                                       Some(mask)
+                                                                   }
+                                                                   else {
+                                                                       None
+                                                                   })
+                                                       .collect();
+
+    for mask in &mut masks {
+        let minima = mask.coord_of(mask.iter().position(|&x| x).unwrap());
+        let mut coords_to_try = get_unmarked_adjacents(minima, mask);
+        coords_to_try.retain(|&adj| input[adj] != 9 && input[adj] > input[minima]);
+
+        while !coords_to_try.is_empty() {
+            coords_to_try.iter().for_each(|&coord| mask[coord] = true );
+            if let Some(current) = coords_to_try.pop() {
+                let mut adjacents = get_unmarked_adjacents(current, mask);
+                adjacents.retain(|&adj| input[adj] != 9 && input[adj] > input[current]);
+                coords_to_try.append(&mut adjacents);
+                coords_to_try.sort();
+                coords_to_try.dedup();
+            }
+        }
+    }
+
+    let mut basin_sizes : Vec<usize> = masks.iter().map(|mask| mask.iter().filter(|&x| *x).count()).collect();
+    basin_sizes.sort();
+    basin_sizes.reverse();
+    basin_sizes
+}
+
+pub type Input = Grid<u32>;
+
+/// Reads the heightmap.
+pub fn parse(path: &Path) -> Result<Input> {
+    input::grid(path)
+}
+
+/// Sum of the risk levels of every low point.
+pub fn part1(heightmap: &Input) -> Result<Answer> {
+    Ok(risk_level_sum(heightmap).into())
+}
+
+/// Product of the sizes of the three largest basins.
+pub fn part2(heightmap: &Input) -> Result<Answer> {
+    Ok(basin_sizes(heightmap).iter().take(3).product::<usize>().into())
+}
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input = Input;
+
+    fn parse(path: &Path) -> Result<Input> {
+        parse(path)
+    }
+
+    fn part1(input: &Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing;
+use day9::Day9;
+
+#[test]
+fn bundled_examples() {
+    testing::check_examples::<Day9>(env!("CARGO_MANIFEST_DIR"));
+}